@@ -1,7 +1,8 @@
-use ducc::{Elements, Properties, Value};
+use ducc::{Array, Elements, Function, Object, Properties, Value};
 use error::{Error, Result};
 use serde;
 use serde::de::IntoDeserializer;
+use std::vec;
 
 pub struct Deserializer<'ducc> {
     pub value: Value<'ducc>,
@@ -33,14 +34,27 @@ impl<'ducc, 'de> serde::Deserializer<'de> for Deserializer<'ducc> {
                 }
             },
             Value::Object(v) => {
-                let len = v.len()? as usize;
-                let mut deserializer = MapDeserializer(v.properties(), None);
-                let map = visitor.visit_map(&mut deserializer)?;
-                let remaining = deserializer.0.count();
-                if remaining == 0 {
-                    Ok(map)
+                if is_js_map(&v)? {
+                    let entries = js_map_entries(&v)?;
+                    let len = entries.len();
+                    let mut deserializer = JsMapDeserializer(entries.into_iter(), None);
+                    let map = visitor.visit_map(&mut deserializer)?;
+                    let remaining = deserializer.0.count();
+                    if remaining == 0 {
+                        Ok(map)
+                    } else {
+                        Err(serde::de::Error::invalid_length(len, &"fewer elements in map"))
+                    }
                 } else {
-                    Err(serde::de::Error::invalid_length(len, &"fewer elements in array"))
+                    let len = v.len()? as usize;
+                    let mut deserializer = MapDeserializer(v.properties(), None);
+                    let map = visitor.visit_map(&mut deserializer)?;
+                    let remaining = deserializer.0.count();
+                    if remaining == 0 {
+                        Ok(map)
+                    } else {
+                        Err(serde::de::Error::invalid_length(len, &"fewer elements in array"))
+                    }
                 }
             },
             Value::Bytes(v) => visitor.visit_bytes(&v.to_vec()),
@@ -97,6 +111,42 @@ impl<'ducc, 'de> serde::Deserializer<'de> for Deserializer<'ducc> {
         visitor.visit_enum(EnumDeserializer { variant, value })
     }
 
+    #[cfg(feature = "128bit")]
+    #[inline]
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>
+    {
+        match self.value {
+            Value::Number(v) => visitor.visit_i128(v as i128),
+            Value::String(v) => {
+                let s = v.to_string()?;
+                let n: i128 = s.parse()
+                    .map_err(|_| serde::de::Error::custom("invalid i128 string"))?;
+                visitor.visit_i128(n)
+            },
+            _ => Err(serde::de::Error::custom("expected a number or numeric string for i128")),
+        }
+    }
+
+    #[cfg(feature = "128bit")]
+    #[inline]
+    fn deserialize_u128<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>
+    {
+        match self.value {
+            Value::Number(v) => visitor.visit_u128(v as u128),
+            Value::String(v) => {
+                let s = v.to_string()?;
+                let n: u128 = s.parse()
+                    .map_err(|_| serde::de::Error::custom("invalid u128 string"))?;
+                visitor.visit_u128(n)
+            },
+            _ => Err(serde::de::Error::custom("expected a number or numeric string for u128")),
+        }
+    }
+
     forward_to_deserialize_any! {
         bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes
         byte_buf unit unit_struct newtype_struct seq tuple
@@ -171,6 +221,83 @@ impl<'ducc, 'de> serde::de::MapAccess<'de> for MapDeserializer<'ducc> {
 }
 
 
+// Returns `true` if `object` is a JavaScript `Map` instance, recognized by its constructor's name,
+// in which case its entries (rather than its own enumerable properties, which a `Map` does not
+// store its contents in) should be deserialized.
+fn is_js_map<'ducc>(object: &Object<'ducc>) -> Result<bool> {
+    let ctor_object = match object.get("constructor")? {
+        Value::Function(f) => f.into_object(),
+        Value::Object(o) => o,
+        _ => return Ok(false),
+    };
+
+    match ctor_object.get("name")? {
+        Value::String(name) => Ok(name.to_string()? == "Map"),
+        _ => Ok(false),
+    }
+}
+
+fn js_map_entries<'ducc>(object: &Object<'ducc>) -> Result<Vec<(Value<'ducc>, Value<'ducc>)>> {
+    let entries_fn: Function = object.get("entries")?;
+    let iterator: Object = entries_fn.call_method(object.clone(), ())?;
+    let next_fn: Function = iterator.get("next")?;
+
+    let mut entries = Vec::new();
+    loop {
+        let step: Object = next_fn.call_method(iterator.clone(), ())?;
+        let done: bool = step.get("done")?;
+        if done {
+            break;
+        }
+
+        let pair: Array = step.get("value")?;
+        entries.push((pair.get(0)?, pair.get(1)?));
+    }
+
+    Ok(entries)
+}
+
+struct JsMapDeserializer<'ducc>(
+    vec::IntoIter<(Value<'ducc>, Value<'ducc>)>,
+    Option<Value<'ducc>>,
+);
+
+impl<'ducc, 'de> serde::de::MapAccess<'de> for JsMapDeserializer<'ducc> {
+    type Error = Error;
+
+    fn next_key_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: serde::de::DeserializeSeed<'de>
+    {
+        match self.0.next() {
+            Some((key, value)) => {
+                self.1 = Some(value);
+                seed.deserialize(Deserializer { value: key }).map(Some)
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<T>(&mut self, seed: T) -> Result<T::Value>
+    where
+        T: serde::de::DeserializeSeed<'de>
+    {
+        match self.1.take() {
+            Some(value) => seed.deserialize(Deserializer { value }),
+            None => Err(serde::de::Error::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        let (lower, upper) = self.0.size_hint();
+        match upper {
+            Some(upper) if lower == upper => Some(upper),
+            _ => None,
+        }
+    }
+}
+
+
 struct EnumDeserializer<'ducc> {
     variant: String,
     value: Option<Value<'ducc>>,
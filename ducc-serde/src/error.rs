@@ -1,29 +1,50 @@
-use ducc::Error as DuccError;
+use ducc::{Error as DuccError, ResultExt};
 use serde;
 use std::fmt;
 use std::error::Error as StdError;
 use std::result::Result as StdResult;
 
 #[derive(Debug)]
-pub struct Error(DuccError);
+pub enum Error {
+    Ducc(DuccError),
+    /// An integer value could not be represented exactly as a JavaScript number (an IEEE 754
+    /// double), because its magnitude exceeds 2^53 - 1. Only returned by the "checked" entry
+    /// points (e.g. `to_value_checked`); the default, lossy entry points never produce this.
+    NumberCastError {
+        value: i128,
+    },
+}
 
 pub type Result<T> = StdResult<T, Error>;
 
 impl From<DuccError> for Error {
     fn from(err: DuccError) -> Error {
-        Error(err)
+        Error::Ducc(err)
     }
 }
 
 impl From<Error> for DuccError {
     fn from(err: Error) -> DuccError {
-        err.0
+        match err {
+            Error::Ducc(err) => err,
+            Error::NumberCastError { value } => {
+                DuccError::to_js_conversion("integer", "number").js_err_context(format!(
+                    "{} cannot be represented exactly as a JavaScript number",
+                    value,
+                ))
+            },
+        }
     }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        self.0.fmt(fmt)
+        match *self {
+            Error::Ducc(ref err) => err.fmt(fmt),
+            Error::NumberCastError { value } => {
+                write!(fmt, "{} cannot be represented exactly as a JavaScript number", value)
+            },
+        }
     }
 }
 
@@ -35,12 +56,12 @@ impl StdError for Error {
 
 impl serde::ser::Error for Error {
     fn custom<T: fmt::Display>(_msg: T) -> Self {
-        Error(DuccError::to_js_conversion("serde", "value"))
+        Error::Ducc(DuccError::to_js_conversion("serde", "value"))
     }
 }
 
 impl serde::de::Error for Error {
     fn custom<T: fmt::Display>(_msg: T) -> Self {
-        Error(DuccError::to_js_conversion("value", "serde"))
+        Error::Ducc(DuccError::to_js_conversion("value", "serde"))
     }
 }
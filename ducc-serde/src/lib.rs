@@ -9,10 +9,25 @@ pub mod de;
 pub use error::{Error, Result};
 pub use ser::Serializer;
 
-use ducc::{Ducc, Value, Result as DuccResult};
+use ducc::{Ducc, Error as DuccError, Object, Value, Result as DuccResult};
+use serde::de::DeserializeOwned;
 
 pub fn to_value<T: serde::Serialize>(ducc: &Ducc, value: T) -> DuccResult<Value> {
-    let serializer = ser::Serializer { ducc };
+    let serializer = ser::Serializer { ducc, checked: false, use_js_map: false };
+    Ok(value.serialize(serializer)?)
+}
+
+/// Like `to_value`, but integers that cannot be represented exactly as a JavaScript number (i.e.
+/// their magnitude exceeds 2^53 - 1) produce an error instead of silently losing precision.
+pub fn to_value_checked<T: serde::Serialize>(ducc: &Ducc, value: T) -> DuccResult<Value> {
+    let serializer = ser::Serializer { ducc, checked: true, use_js_map: false };
+    Ok(value.serialize(serializer)?)
+}
+
+/// Like `to_value`, but a serialized Rust map (a `HashMap`, `BTreeMap`, etc.) becomes a JavaScript
+/// `Map` instance instead of a plain object, so non-string keys survive the round trip intact.
+pub fn to_value_as_js_map<T: serde::Serialize>(ducc: &Ducc, value: T) -> DuccResult<Value> {
+    let serializer = ser::Serializer { ducc, checked: false, use_js_map: true };
     Ok(value.serialize(serializer)?)
 }
 
@@ -20,3 +35,72 @@ pub fn from_value<'de, T: serde::Deserialize<'de>>(value: Value<'de>) -> DuccRes
     let deserializer = de::Deserializer { value };
     Ok(T::deserialize(deserializer)?)
 }
+
+/// Serializes a Rust value straight to CBOR bytes, by composing `to_value` with
+/// `Ducc::cbor_encode`.
+pub fn to_cbor<T: serde::Serialize>(ducc: &Ducc, value: T) -> DuccResult<Vec<u8>> {
+    ducc.cbor_encode(to_value(ducc, value)?)
+}
+
+/// Deserializes a Rust value straight from CBOR bytes, by composing `Ducc::cbor_decode` with
+/// `from_value`.
+pub fn from_cbor<T: DeserializeOwned>(ducc: &Ducc, bytes: &[u8]) -> DuccResult<T> {
+    from_value(ducc.cbor_decode(bytes)?)
+}
+
+/// Serializes `value` and copies its own enumerable properties onto `object`, as if each field had
+/// been assigned with `Object::set` by hand. This makes it one call to inject a whole config
+/// struct into `ducc.globals()` (or any other existing object) before `exec`.
+///
+/// # Errors
+///
+/// This function returns an error if `value` does not serialize to a JS object (for example, a
+/// tuple, a sequence, or a primitive).
+pub fn extend_from<T: serde::Serialize>(ducc: &Ducc, object: &Object, value: &T) -> DuccResult<()> {
+    match to_value(ducc, value)? {
+        Value::Object(source) => {
+            for property in source.properties::<Value, Value>() {
+                let (key, value) = property?;
+                object.set(key, value)?;
+            }
+            Ok(())
+        },
+        _ => Err(DuccError::to_js_conversion("value", "object")),
+    }
+}
+
+/// Extension trait adding an `extend_from` entry point directly to `Object`, so callers with a
+/// `Serialize` type don't need to reach for the free function.
+pub trait ObjectExt<'ducc> {
+    fn extend_from<T: serde::Serialize>(&self, ducc: &'ducc Ducc, value: &T) -> DuccResult<()>;
+}
+
+impl<'ducc> ObjectExt<'ducc> for Object<'ducc> {
+    fn extend_from<T: serde::Serialize>(&self, ducc: &'ducc Ducc, value: &T) -> DuccResult<()> {
+        extend_from(ducc, self, value)
+    }
+}
+
+/// Extension trait adding a `to_value` entry point directly to `Ducc`, so callers with a
+/// `Serialize` type don't need to reach for the free function.
+pub trait ToValueExt {
+    fn to_value<'ducc, T: serde::Serialize>(&'ducc self, value: T) -> DuccResult<Value<'ducc>>;
+}
+
+impl ToValueExt for Ducc {
+    fn to_value<'ducc, T: serde::Serialize>(&'ducc self, value: T) -> DuccResult<Value<'ducc>> {
+        to_value(self, value)
+    }
+}
+
+/// Extension trait adding an `into_serde` entry point directly to `Value`, so callers with a
+/// `DeserializeOwned` type don't need to reach for the free function.
+pub trait IntoSerdeExt<'ducc> {
+    fn into_serde<T: DeserializeOwned>(self) -> DuccResult<T>;
+}
+
+impl<'ducc> IntoSerdeExt<'ducc> for Value<'ducc> {
+    fn into_serde<T: DeserializeOwned>(self) -> DuccResult<T> {
+        from_value(self)
+    }
+}
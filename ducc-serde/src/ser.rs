@@ -1,9 +1,33 @@
-use ducc::{Array, Ducc, Object, String as DuccString, Value};
+use ducc::{Array, Ducc, Function, Object, String as DuccString, Value};
 use serde;
-use super::{Error, Result, to_value};
+use super::{Error, Result};
+
+/// The largest integer magnitude that can be represented exactly as an IEEE 754 double (2^53 - 1).
+const MAX_SAFE_INTEGER: i64 = 9_007_199_254_740_991;
+const MAX_SAFE_INTEGER_U64: u64 = 9_007_199_254_740_991;
+
+fn serialize_value<'ducc, T>(
+    ducc: &'ducc Ducc,
+    checked: bool,
+    use_js_map: bool,
+    value: &T,
+) -> Result<Value<'ducc>>
+where
+    T: ?Sized + serde::Serialize,
+{
+    value.serialize(Serializer { ducc, checked, use_js_map })
+}
 
 pub struct Serializer<'ducc> {
     pub ducc: &'ducc Ducc,
+    /// When `true`, integers that cannot be represented exactly as a JavaScript number produce a
+    /// `Error::NumberCastError` instead of silently losing precision. Defaults to `false`.
+    pub checked: bool,
+    /// When `true`, a serialized Rust map (`serialize_map`, e.g. a `HashMap` or `BTreeMap`) becomes
+    /// a JavaScript `Map` instance, preserving non-string keys, instead of a plain object whose keys
+    /// are coerced to property strings. Struct fields (`serialize_struct`) always become a plain
+    /// object regardless of this setting. Defaults to `false`.
+    pub use_js_map: bool,
 }
 
 impl<'ducc> serde::Serializer for Serializer<'ducc> {
@@ -40,6 +64,9 @@ impl<'ducc> serde::Serializer for Serializer<'ducc> {
 
     #[inline]
     fn serialize_i64(self, value: i64) -> Result<Value<'ducc>> {
+        if self.checked && (value > MAX_SAFE_INTEGER || value < -MAX_SAFE_INTEGER) {
+            return Err(Error::NumberCastError { value: value as i128 });
+        }
         self.serialize_f64(value as f64)
     }
 
@@ -60,9 +87,24 @@ impl<'ducc> serde::Serializer for Serializer<'ducc> {
 
     #[inline]
     fn serialize_u64(self, value: u64) -> Result<Value<'ducc>> {
+        if self.checked && value > MAX_SAFE_INTEGER_U64 {
+            return Err(Error::NumberCastError { value: value as i128 });
+        }
         self.serialize_f64(value as f64)
     }
 
+    #[cfg(feature = "128bit")]
+    #[inline]
+    fn serialize_i128(self, value: i128) -> Result<Value<'ducc>> {
+        Ok(Value::String(self.ducc.create_string(&value.to_string())?))
+    }
+
+    #[cfg(feature = "128bit")]
+    #[inline]
+    fn serialize_u128(self, value: u128) -> Result<Value<'ducc>> {
+        Ok(Value::String(self.ducc.create_string(&value.to_string())?))
+    }
+
     #[inline]
     fn serialize_f32(self, value: f32) -> Result<Value<'ducc>> {
         self.serialize_f64(value as f64)
@@ -134,7 +176,7 @@ impl<'ducc> serde::Serializer for Serializer<'ducc> {
     {
         let object = self.ducc.create_object();
         let variant = self.ducc.create_string(variant)?;
-        let value = to_value(self.ducc, value)?;
+        let value = serialize_value(self.ducc, self.checked, self.use_js_map, value)?;
         object.set(variant, value)?;
         Ok(Value::Object(object))
     }
@@ -156,6 +198,8 @@ impl<'ducc> serde::Serializer for Serializer<'ducc> {
         let array = self.ducc.create_array();
         Ok(SerializeVec {
             ducc: self.ducc,
+            checked: self.checked,
+            use_js_map: self.use_js_map,
             array,
         })
     }
@@ -184,22 +228,40 @@ impl<'ducc> serde::Serializer for Serializer<'ducc> {
         let array = self.ducc.create_array();
         Ok(SerializeTupleVariant {
             ducc: self.ducc,
+            checked: self.checked,
+            use_js_map: self.use_js_map,
             array,
             name,
         })
     }
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
-        let object = self.ducc.create_object();
+        let target = if self.use_js_map {
+            let map_ctor: Function = self.ducc.globals().get("Map")?;
+            let instance: Object = map_ctor.call_new(())?;
+            let set: Function = instance.get("set")?;
+            MapTarget::JsMap { instance, set }
+        } else {
+            MapTarget::Object(self.ducc.create_object())
+        };
+
         Ok(SerializeMap {
             ducc: self.ducc,
-            object,
+            checked: self.checked,
+            use_js_map: self.use_js_map,
+            target,
             next_key: None,
         })
     }
 
-    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
-        self.serialize_map(Some(len))
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Ok(SerializeMap {
+            ducc: self.ducc,
+            checked: self.checked,
+            use_js_map: self.use_js_map,
+            target: MapTarget::Object(self.ducc.create_object()),
+            next_key: None,
+        })
     }
 
     fn serialize_struct_variant(
@@ -213,6 +275,8 @@ impl<'ducc> serde::Serializer for Serializer<'ducc> {
         let object = self.ducc.create_object();
         Ok(SerializeStructVariant {
             ducc: self.ducc,
+            checked: self.checked,
+            use_js_map: self.use_js_map,
             object,
             name,
         })
@@ -221,6 +285,8 @@ impl<'ducc> serde::Serializer for Serializer<'ducc> {
 
 pub struct SerializeVec<'ducc> {
     ducc: &'ducc Ducc,
+    checked: bool,
+    use_js_map: bool,
     array: Array<'ducc>,
 }
 
@@ -232,7 +298,7 @@ impl<'ducc> serde::ser::SerializeSeq for SerializeVec<'ducc> {
     where
         T: ?Sized + serde::Serialize,
     {
-        self.array.push(to_value(self.ducc, value)?)?;
+        self.array.push(serialize_value(self.ducc, self.checked, self.use_js_map, value)?)?;
         Ok(())
     }
 
@@ -275,6 +341,8 @@ impl<'ducc> serde::ser::SerializeTupleStruct for SerializeVec<'ducc> {
 
 pub struct SerializeTupleVariant<'ducc> {
     ducc: &'ducc Ducc,
+    checked: bool,
+    use_js_map: bool,
     name: DuccString<'ducc>,
     array: Array<'ducc>,
 }
@@ -287,7 +355,7 @@ impl<'ducc> serde::ser::SerializeTupleVariant for SerializeTupleVariant<'ducc> {
     where
         T: ?Sized + serde::Serialize,
     {
-        self.array.push(to_value(self.ducc, value)?)?;
+        self.array.push(serialize_value(self.ducc, self.checked, self.use_js_map, value)?)?;
         Ok(())
     }
 
@@ -298,9 +366,21 @@ impl<'ducc> serde::ser::SerializeTupleVariant for SerializeTupleVariant<'ducc> {
     }
 }
 
+/// Where a serialized Rust map's entries are written: a plain JavaScript object (string-keyed), or
+/// a JavaScript `Map` instance (arbitrary `Value` keys).
+enum MapTarget<'ducc> {
+    Object(Object<'ducc>),
+    JsMap {
+        instance: Object<'ducc>,
+        set: Function<'ducc>,
+    },
+}
+
 pub struct SerializeMap<'ducc> {
     ducc: &'ducc Ducc,
-    object: Object<'ducc>,
+    checked: bool,
+    use_js_map: bool,
+    target: MapTarget<'ducc>,
     next_key: Option<Value<'ducc>>
 }
 
@@ -312,7 +392,7 @@ impl<'ducc> serde::ser::SerializeMap for SerializeMap<'ducc> {
     where
         T: ?Sized + serde::Serialize,
     {
-        self.next_key = Some(to_value(self.ducc, key)?);
+        self.next_key = Some(serialize_value(self.ducc, self.checked, self.use_js_map, key)?);
         Ok(())
     }
 
@@ -321,12 +401,21 @@ impl<'ducc> serde::ser::SerializeMap for SerializeMap<'ducc> {
         T: ?Sized + serde::Serialize,
     {
         let key = self.next_key.take().expect("serialize_value called before serialize_key");
-        self.object.set(key, to_value(self.ducc, value)?)?;
+        let value = serialize_value(self.ducc, self.checked, self.use_js_map, value)?;
+        match self.target {
+            MapTarget::Object(ref object) => object.set(key, value)?,
+            MapTarget::JsMap { ref instance, ref set } => {
+                set.call_method::<_, _, ()>(instance.clone(), (key, value))?
+            },
+        }
         Ok(())
     }
 
     fn end(self) -> Result<Value<'ducc>> {
-        Ok(Value::Object(self.object))
+        match self.target {
+            MapTarget::Object(object) => Ok(Value::Object(object)),
+            MapTarget::JsMap { instance, .. } => Ok(Value::Object(instance)),
+        }
     }
 }
 
@@ -349,6 +438,8 @@ impl<'ducc> serde::ser::SerializeStruct for SerializeMap<'ducc> {
 
 pub struct SerializeStructVariant<'ducc> {
     ducc: &'ducc Ducc,
+    checked: bool,
+    use_js_map: bool,
     object: Object<'ducc>,
     name: DuccString<'ducc>,
 }
@@ -361,7 +452,7 @@ impl<'ducc> serde::ser::SerializeStructVariant for SerializeStructVariant<'ducc>
     where
         T: ?Sized + serde::Serialize,
     {
-        self.object.set(key, to_value(self.ducc, value)?)?;
+        self.object.set(key, serialize_value(self.ducc, self.checked, self.use_js_map, value)?)?;
         Ok(())
     }
 
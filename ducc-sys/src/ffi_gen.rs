@@ -4,6 +4,7 @@ fn main() {
     let mut builder = bindgen::Builder::default()
         .header("duktape/wrapper.h")
         .clang_arg("-Iduktape")
+        .clang_arg("-Iduktape/extras/cbor")
         .clang_arg("-std=c99");
 
     if let Ok(sdk_path) = std::env::var("DUCC_SYSTEM_SDK_PATH") {
@@ -0,0 +1,354 @@
+use error::{Error, Result, ResultExt};
+use ffi;
+use object::Object;
+use std::marker::PhantomData;
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use types::Ref;
+use util::protect_duktape_closure;
+use value::{FromValue, ToValue, Value, Values};
+
+/// Reference to a JavaScript array.
+#[derive(Clone, Debug)]
+pub struct Array<'ducc>(pub(crate) Ref<'ducc>);
+
+impl<'ducc> Array<'ducc> {
+    /// Consumes the array and returns it as a JavaScript object. This is inexpensive, since an
+    /// array *is* an object.
+    pub fn into_object(self) -> Object<'ducc> {
+        Object(self.0)
+    }
+
+    /// Get the value using the given array index. Returns `Value::Undefined` if no element at the
+    /// index exists.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    ///
+    /// * `FromValue::from_value` fails for the element
+    pub fn get<V: FromValue<'ducc>>(&self, index: u32) -> Result<V> {
+        let ducc = self.0.ducc;
+        let value = unsafe {
+            assert_stack!(ducc.ctx, 0, {
+                ducc.push_ref(&self.0);
+                protect_duktape_closure(ducc.ctx, 1, 1, |ctx| {
+                    ffi::duk_get_prop_index(ctx, -1, index);
+                })?;
+                ducc.pop_value()
+            })
+        };
+        V::from_value(value, ducc)
+    }
+
+    /// Sets an array element using the given index and value.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    ///
+    /// * `ToValue::to_value` fails for the value
+    pub fn set<V: ToValue<'ducc>>(&self, index: u32, value: V) -> Result<()> {
+        let ducc = self.0.ducc;
+        let value = value.to_value(ducc)?;
+        unsafe {
+            assert_stack!(ducc.ctx, 0, {
+                ducc.push_ref(&self.0);
+                ducc.push_value(value);
+                protect_duktape_closure(ducc.ctx, 2, 0, |ctx| {
+                    ffi::duk_put_prop_index(ctx, -2, index);
+                })
+            })
+        }
+    }
+
+    /// Returns the number of elements in the array using the calculation
+    /// `Math.floor(ToNumber(array.length))`. This function can return an error if the `ToNumber`
+    /// implementation fails or if the `length` getter fails.
+    pub fn len(&self) -> Result<usize> {
+        let ducc = self.0.ducc;
+        unsafe {
+            assert_stack!(ducc.ctx, 0, {
+                ducc.push_ref(&self.0);
+                protect_duktape_closure(ducc.ctx, 1, 0, |ctx| {
+                    ffi::duk_get_length(ctx, -1)
+                })
+            })
+        }
+    }
+
+    /// Pushes an element to the end of the array. This is a shortcut for `set` using `len` as the
+    /// index.
+    pub fn push<V: ToValue<'ducc>>(&self, value: V) -> Result<()> {
+        self.set(self.len()? as u32, value)
+    }
+
+    /// Removes and returns the last element of the array, shrinking the array's `length` by one.
+    /// Equivalent to JavaScript's `Array.prototype.pop`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    ///
+    /// * `FromValue::from_value` fails for the removed element
+    pub fn pop<V: FromValue<'ducc>>(&self) -> Result<V> {
+        self.clone().into_object().call_prop("pop", ())
+    }
+
+    /// Removes and returns the first element of the array, shifting every subsequent element down
+    /// by one index. Equivalent to JavaScript's `Array.prototype.shift`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    ///
+    /// * `FromValue::from_value` fails for the removed element
+    pub fn shift<V: FromValue<'ducc>>(&self) -> Result<V> {
+        self.clone().into_object().call_prop("shift", ())
+    }
+
+    /// Inserts `value` at the front of the array, shifting every existing element up by one index.
+    /// Equivalent to JavaScript's `Array.prototype.unshift`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    ///
+    /// * `ToValue::to_value` fails for the value
+    pub fn unshift<V: ToValue<'ducc>>(&self, value: V) -> Result<()> {
+        self.clone().into_object().call_prop("unshift", (value,))
+    }
+
+    /// Removes `delete_count` elements starting at `start`, replacing them in place with the
+    /// elements of `replacement`, and returns the removed elements as a new array. Equivalent to
+    /// JavaScript's `Array.prototype.splice`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    ///
+    /// * `ToValue::to_value` fails for any element of `replacement`
+    pub fn splice<V, I>(&self, start: i32, delete_count: usize, replacement: I) -> Result<Array<'ducc>>
+    where
+        V: ToValue<'ducc>,
+        I: IntoIterator<Item = V>,
+    {
+        let ducc = self.0.ducc;
+        let mut args = vec![Value::Number(start as f64), Value::Number(delete_count as f64)];
+        for value in replacement {
+            args.push(value.to_value(ducc)?);
+        }
+        self.clone().into_object().call_prop("splice", Values::from_vec(args))
+    }
+
+    /// Inserts `value` at `index`, shifting elements at or after `index` up by one. A thin wrapper
+    /// over `splice`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    ///
+    /// * `ToValue::to_value` fails for the value
+    pub fn insert<V: ToValue<'ducc>>(&self, index: u32, value: V) -> Result<()> {
+        self.splice(index as i32, 0, Some(value))?;
+        Ok(())
+    }
+
+    /// Removes and returns the element at `index`, shifting every subsequent element down by one.
+    /// A thin wrapper over `splice`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    ///
+    /// * `FromValue::from_value` fails for the removed element
+    pub fn remove<V: FromValue<'ducc>>(&self, index: u32) -> Result<V> {
+        let removed = self.splice::<Value, _>(index as i32, 1, None)?;
+        removed.get(0)
+    }
+
+    /// Appends every element of `values` to the end of the array in a single pass: the array
+    /// reference is pushed once and the starting length is read once, regardless of how many
+    /// elements are appended. Prefer this over repeated `push` calls when appending many elements,
+    /// since `push` re-reads `length` and re-pushes the array reference on every call.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    ///
+    /// * `ToValue::to_value` fails for any value
+    pub fn extend<V, I>(&self, values: I) -> Result<()>
+    where
+        V: ToValue<'ducc>,
+        I: IntoIterator<Item = V>,
+    {
+        let ducc = self.0.ducc;
+        let values = values.into_iter()
+            .map(|value| value.to_value(ducc))
+            .collect::<Result<Vec<_>>>()?;
+        let start = self.len()? as u32;
+
+        unsafe {
+            assert_stack!(ducc.ctx, 0, {
+                ducc.push_ref(&self.0);
+                protect_duktape_closure(ducc.ctx, 1, 0, |ctx| {
+                    ffi::duk_require_stack(ctx, 1);
+                    for (i, value) in values.iter().enumerate() {
+                        ducc.push_value(value.clone());
+                        ffi::duk_put_prop_index(ctx, -2, start + i as u32);
+                    }
+                })
+            })
+        }
+    }
+
+    /// Appends every element of `other` to the end of this array in a single pass. This is a
+    /// shortcut for `extend` using `other`'s elements.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    ///
+    /// * Reading an element of `other` fails
+    pub fn append(&self, other: &Array<'ducc>) -> Result<()> {
+        let values = other.clone().elements::<Value>().collect::<Result<Vec<_>>>()?;
+        self.extend(values)
+    }
+
+    /// Converts the array into a fixed-size Rust array `[V; N]`, checking that the array's length
+    /// is exactly `N`. Prefer this over collecting `elements()` into a `Vec` when the expected
+    /// length is known up front, since it avoids the intermediate allocation.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    ///
+    /// * The array's `length` is not exactly `N`
+    /// * `FromValue::from_value` fails for any element
+    pub fn to_fixed<V: FromValue<'ducc>, const N: usize>(&self) -> Result<[V; N]> {
+        let len = self.len()?;
+        if len != N {
+            return Err(
+                Error::from_js_conversion("array", "fixed-size array")
+                    .js_err_context(format!("expected an array of length {}, but got {}", N, len))
+            );
+        }
+
+        // Safety: an array of `MaybeUninit<V>` does not itself require initialization.
+        let mut elements: [MaybeUninit<V>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+
+        // Drops the elements initialized so far if `FromValue::from_value` fails partway through,
+        // so that an error doesn't leak the elements already converted.
+        struct Guard<'a, V> {
+            elements: &'a mut [MaybeUninit<V>],
+            initialized: usize,
+        }
+
+        impl<'a, V> Drop for Guard<'a, V> {
+            fn drop(&mut self) {
+                for element in &mut self.elements[..self.initialized] {
+                    unsafe { ptr::drop_in_place(element.as_mut_ptr()); }
+                }
+            }
+        }
+
+        let mut guard = Guard { elements: &mut elements, initialized: 0 };
+        for i in 0..N {
+            guard.elements[i] = MaybeUninit::new(self.get(i as u32)?);
+            guard.initialized += 1;
+        }
+        mem::forget(guard);
+
+        // Safety: every element has just been initialized above.
+        Ok(unsafe { (&elements as *const [MaybeUninit<V>; N] as *const [V; N]).read() })
+    }
+
+    /// Returns an iterator over the array's indexable values.
+    pub fn elements<V: FromValue<'ducc>>(self) -> Elements<'ducc, V> {
+        Elements {
+            array: self,
+            index: 0,
+            back: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+pub struct Elements<'ducc, V> {
+    array: Array<'ducc>,
+    // The front cursor: the index of the next element `next` will yield.
+    index: u32,
+    // The back cursor: one past the index of the next element `next_back` will yield. `None` until
+    // the array's length has been fetched, at which point it's initialized to that length; caching
+    // it here (rather than re-querying `array.len()` on every `next_back` call) is what lets the two
+    // cursors agree on where the array ends even if it's mutated mid-iteration.
+    back: Option<u32>,
+    _phantom: PhantomData<V>,
+}
+
+impl<'ducc, V> Elements<'ducc, V> {
+    // Ensures `back` is populated, fetching and caching the array's length on first call. Surfaces
+    // the length lookup's error, if any, as the caller's next yielded item.
+    fn ensure_back(&mut self) -> Option<Result<V>> {
+        if self.back.is_none() {
+            self.back = Some(match self.array.len() {
+                Ok(len) => len as u32,
+                Err(err) => return Some(Err(err)),
+            });
+        }
+
+        None
+    }
+}
+
+impl<'ducc, V: FromValue<'ducc>> Iterator for Elements<'ducc, V> {
+    type Item = Result<V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.ensure_back() {
+            return Some(err);
+        }
+
+        if self.index >= self.back.unwrap() {
+            return None;
+        }
+
+        let result = self.array.get(self.index);
+        self.index += 1;
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = match self.back {
+            Some(back) => back.saturating_sub(self.index) as usize,
+            None => self.array.len().map(|len| len - self.index as usize).unwrap_or(0),
+        };
+        (len, Some(len))
+    }
+}
+
+impl<'ducc, V: FromValue<'ducc>> DoubleEndedIterator for Elements<'ducc, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.ensure_back() {
+            return Some(err);
+        }
+
+        let back = self.back.unwrap();
+        if self.index >= back {
+            return None;
+        }
+
+        let back = back - 1;
+        self.back = Some(back);
+        Some(self.array.get(back))
+    }
+}
+
+impl<'ducc, V: FromValue<'ducc>> ExactSizeIterator for Elements<'ducc, V> {
+    fn len(&self) -> usize {
+        match self.back {
+            Some(back) => (back - self.index) as usize,
+            None => self.array.len().map(|len| len - self.index as usize).unwrap_or(0),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use error::Result;
+use ffi;
+use object::Object;
+use std::slice;
+use types::Ref;
+use util::protect_duktape_closure;
+
+/// Reference to a JavaScript `ArrayBuffer`, the fixed-length binary backing store that typed array
+/// views (`Uint8Array`, `Float64Array`, etc.) and `DataView`s read and write through.
+#[derive(Clone, Debug)]
+pub struct ArrayBuffer<'ducc>(pub(crate) Ref<'ducc>);
+
+impl<'ducc> ArrayBuffer<'ducc> {
+    /// Consumes the array buffer and returns it as a JavaScript object. This is inexpensive, since
+    /// an array buffer *is* an object.
+    pub fn into_object(self) -> Object<'ducc> {
+        Object(self.0)
+    }
+
+    /// Returns the length of this buffer, in bytes.
+    pub fn len(&self) -> usize {
+        let ducc = self.0.ducc;
+        unsafe {
+            assert_stack!(ducc.ctx, 0, {
+                ducc.push_ref(&self.0);
+                assert!(ffi::duk_is_buffer_data(ducc.ctx, -1) != 0);
+                let mut len = 0;
+                ffi::duk_get_buffer_data(ducc.ctx, -1, &mut len);
+                ffi::duk_pop(ducc.ctx);
+                len as usize
+            })
+        }
+    }
+}
+
+pub(crate) unsafe fn push_array_buffer(ctx: *mut ffi::duk_context, len: usize) -> Result<()> {
+    protect_duktape_closure(ctx, 0, 1, |ctx| {
+        ffi::duk_require_stack(ctx, 2);
+        ffi::duk_push_fixed_buffer(ctx, len as ffi::duk_size_t);
+        ffi::duk_push_buffer_object(ctx, -1, 0, len as ffi::duk_size_t, ffi::DUK_BUFOBJ_ARRAYBUFFER);
+        ffi::duk_remove(ctx, -2);
+    })
+}
+
+// Inspects the object at `idx` (which must already be known to be buffer-backed, via
+// `duk_is_buffer_data`) and returns `true` if it is a plain `ArrayBuffer`, as opposed to one of the
+// typed array views handled by `typed_array_kind_of` or a `DataView`.
+pub(crate) unsafe fn is_array_buffer(ctx: *mut ffi::duk_context, idx: ffi::duk_idx_t) -> bool {
+    let idx = ffi::duk_normalize_index(ctx, idx);
+
+    assert_stack!(ctx, 0, {
+        ffi::duk_require_stack(ctx, 2);
+        ffi::duk_get_prop_string(ctx, idx, cstr!("constructor"));
+        ffi::duk_get_prop_string(ctx, -1, cstr!("name"));
+        let mut len = 0;
+        let ptr = ffi::duk_get_lstring(ctx, -1, &mut len);
+        let is_array_buffer = !ptr.is_null()
+            && slice::from_raw_parts(ptr as *const u8, len as usize) == b"ArrayBuffer";
+        ffi::duk_pop_2(ctx);
+        is_array_buffer
+    })
+}
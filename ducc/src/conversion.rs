@@ -1,4 +1,5 @@
 use array::Array;
+use array_buffer::ArrayBuffer;
 use bytes::Bytes;
 use ducc::Ducc;
 use error::{Error, Result};
@@ -9,6 +10,9 @@ use std::cmp::{Eq, Ord};
 use std::hash::{BuildHasher, Hash};
 use std::string::String as StdString;
 use string::String;
+use symbol::Symbol;
+use typed_array::{TypedArray, TypedArrayKind, TypedSlice};
+use user_data::AnyUserData;
 use value::{FromValue, FromValues, ToValue, ToValues, Value, Values, Variadic};
 
 impl<'ducc> ToValue<'ducc> for Value<'ducc> {
@@ -125,6 +129,92 @@ impl<'ducc> FromValue<'ducc> for Bytes<'ducc> {
     }
 }
 
+impl<'ducc> ToValue<'ducc> for TypedArray<'ducc> {
+    fn to_value(self, _ducc: &'ducc Ducc) -> Result<Value<'ducc>> {
+        Ok(Value::TypedArray(self))
+    }
+}
+
+impl<'ducc> FromValue<'ducc> for TypedArray<'ducc> {
+    fn from_value(value: Value<'ducc>, _ducc: &'ducc Ducc) -> Result<TypedArray<'ducc>> {
+        match value {
+            Value::TypedArray(t) => Ok(t),
+            value => Err(Error::from_js_conversion(value.type_name(), "TypedArray")),
+        }
+    }
+}
+
+impl<'ducc> ToValue<'ducc> for ArrayBuffer<'ducc> {
+    fn to_value(self, _ducc: &'ducc Ducc) -> Result<Value<'ducc>> {
+        Ok(Value::ArrayBuffer(self))
+    }
+}
+
+impl<'ducc> FromValue<'ducc> for ArrayBuffer<'ducc> {
+    fn from_value(value: Value<'ducc>, _ducc: &'ducc Ducc) -> Result<ArrayBuffer<'ducc>> {
+        match value {
+            Value::ArrayBuffer(a) => Ok(a),
+            value => Err(Error::from_js_conversion(value.type_name(), "ArrayBuffer")),
+        }
+    }
+}
+
+macro_rules! convert_typed_slice {
+    ($prim_ty: ty, $kind: ident, $to_vec: ident) => {
+        impl<'ducc> ToValue<'ducc> for TypedSlice<$prim_ty> {
+            fn to_value(self, ducc: &'ducc Ducc) -> Result<Value<'ducc>> {
+                Ok(Value::TypedArray(ducc.create_typed_array(TypedArrayKind::$kind, &self.0)?))
+            }
+        }
+
+        impl<'ducc> FromValue<'ducc> for TypedSlice<$prim_ty> {
+            fn from_value(value: Value<'ducc>, ducc: &'ducc Ducc) -> Result<Self> {
+                let array = TypedArray::from_value(value, ducc)?;
+                Ok(TypedSlice(array.$to_vec()?))
+            }
+        }
+    }
+}
+
+convert_typed_slice!(i8, Int8, to_vec_i8);
+convert_typed_slice!(u8, Uint8, to_vec_u8);
+convert_typed_slice!(i16, Int16, to_vec_i16);
+convert_typed_slice!(u16, Uint16, to_vec_u16);
+convert_typed_slice!(i32, Int32, to_vec_i32);
+convert_typed_slice!(u32, Uint32, to_vec_u32);
+convert_typed_slice!(f32, Float32, to_vec_f32);
+convert_typed_slice!(f64, Float64, to_vec_f64);
+
+impl<'ducc> ToValue<'ducc> for AnyUserData<'ducc> {
+    fn to_value(self, _ducc: &'ducc Ducc) -> Result<Value<'ducc>> {
+        Ok(Value::UserData(self))
+    }
+}
+
+impl<'ducc> FromValue<'ducc> for AnyUserData<'ducc> {
+    fn from_value(value: Value<'ducc>, _ducc: &'ducc Ducc) -> Result<AnyUserData<'ducc>> {
+        match value {
+            Value::UserData(u) => Ok(u),
+            value => Err(Error::from_js_conversion(value.type_name(), "UserData")),
+        }
+    }
+}
+
+impl<'ducc> ToValue<'ducc> for Symbol<'ducc> {
+    fn to_value(self, _ducc: &'ducc Ducc) -> Result<Value<'ducc>> {
+        Ok(Value::Symbol(self))
+    }
+}
+
+impl<'ducc> FromValue<'ducc> for Symbol<'ducc> {
+    fn from_value(value: Value<'ducc>, _ducc: &'ducc Ducc) -> Result<Symbol<'ducc>> {
+        match value {
+            Value::Symbol(s) => Ok(s),
+            value => Err(Error::from_js_conversion(value.type_name(), "Symbol")),
+        }
+    }
+}
+
 impl<'ducc, K, V, S> ToValue<'ducc> for HashMap<K, V, S>
 where
     K: Eq + Hash + ToValue<'ducc>,
@@ -290,13 +380,91 @@ convert_number!(i16);
 convert_number!(u16);
 convert_number!(i32);
 convert_number!(u32);
-convert_number!(i64);
-convert_number!(u64);
 convert_number!(isize);
 convert_number!(usize);
 convert_number!(f32);
 convert_number!(f64);
 
+// The largest/smallest integers a JavaScript `number` can represent without losing precision
+// (`Number.MAX_SAFE_INTEGER`/`Number.MIN_SAFE_INTEGER`). Also used by the `serde` bridge
+// (`serde_value.rs`) so it can box out-of-range `i64`/`u64` the same way these impls do.
+pub(crate) const MAX_SAFE_INTEGER: i64 = 9_007_199_254_740_991;
+pub(crate) const MIN_SAFE_INTEGER: i64 = -9_007_199_254_740_991;
+
+// Property key marking an object produced by `to_value_lossless` as carrying the exact decimal
+// digits of an `i64`/`u64` that didn't fit in a safe `f64`, rather than being an ordinary object.
+const LOSSLESS_INTEGER_KEY: &str = "__ducc_lossless_integer__";
+
+// Boxes `digits` (the `to_string()` of an out-of-safe-range `i64`/`u64`) into a plain object
+// carrying them verbatim, so `from_value_lossless` can parse them back byte-for-byte instead of
+// round-tripping through a lossy `f64`. Shared with the `serde` bridge for the same reason as
+// `MAX_SAFE_INTEGER` above.
+pub(crate) fn to_value_lossless<'ducc>(ducc: &'ducc Ducc, digits: StdString) -> Result<Value<'ducc>> {
+    let object = ducc.create_object();
+    object.set(LOSSLESS_INTEGER_KEY, digits)?;
+    Ok(Value::Object(object))
+}
+
+// Recovers the decimal digits boxed by `to_value_lossless`, if `value` is such an object.
+pub(crate) fn from_value_lossless(value: &Value) -> Result<Option<StdString>> {
+    match *value {
+        Value::Object(ref object) if object.contains_key(LOSSLESS_INTEGER_KEY)? => {
+            Ok(Some(object.get(LOSSLESS_INTEGER_KEY)?))
+        },
+        _ => Ok(None),
+    }
+}
+
+/// Converts losslessly. A value within `Number.MIN_SAFE_INTEGER..=Number.MAX_SAFE_INTEGER`
+/// becomes a plain JS `number`. A value outside that range can't be represented as an `f64`
+/// without losing digits, so it instead becomes a plain object of the shape
+/// `{ __ducc_lossless_integer__: "<decimal digits>" }` (meaning `typeof` is `"object"`, not
+/// `"number"`, for such values) carrying its exact decimal digits; `FromValue` reverses this.
+impl<'ducc> ToValue<'ducc> for i64 {
+    fn to_value(self, ducc: &'ducc Ducc) -> Result<Value<'ducc>> {
+        if self >= MIN_SAFE_INTEGER && self <= MAX_SAFE_INTEGER {
+            Ok(Value::Number(self as f64))
+        } else {
+            to_value_lossless(ducc, self.to_string())
+        }
+    }
+}
+
+/// Reverses `ToValue for i64`: a plain `number` converts directly, and an object of the shape
+/// `{ __ducc_lossless_integer__: "<decimal digits>" }` has its digits parsed back out.
+impl<'ducc> FromValue<'ducc> for i64 {
+    fn from_value(value: Value<'ducc>, ducc: &'ducc Ducc) -> Result<Self> {
+        if let Some(digits) = from_value_lossless(&value)? {
+            return digits.parse().map_err(|_| Error::from_js_conversion("object", "i64"));
+        }
+
+        Ok(ducc.coerce_number(value)? as i64)
+    }
+}
+
+/// Converts losslessly, the same as `ToValue for i64` (see there for the out-of-range
+/// representation), just unsigned.
+impl<'ducc> ToValue<'ducc> for u64 {
+    fn to_value(self, ducc: &'ducc Ducc) -> Result<Value<'ducc>> {
+        if self <= MAX_SAFE_INTEGER as u64 {
+            Ok(Value::Number(self as f64))
+        } else {
+            to_value_lossless(ducc, self.to_string())
+        }
+    }
+}
+
+/// Reverses `ToValue for u64`; see `FromValue for i64` for the boxed-object representation.
+impl<'ducc> FromValue<'ducc> for u64 {
+    fn from_value(value: Value<'ducc>, ducc: &'ducc Ducc) -> Result<Self> {
+        if let Some(digits) = from_value_lossless(&value)? {
+            return digits.parse().map_err(|_| Error::from_js_conversion("object", "u64"));
+        }
+
+        Ok(ducc.coerce_number(value)? as u64)
+    }
+}
+
 impl<'ducc> ToValues<'ducc> for Values<'ducc> {
     fn to_values(self, _ducc: &'ducc Ducc) -> Result<Values<'ducc>> {
         Ok(self)
@@ -8,15 +8,27 @@
 //   see `ensure_exec_timeout_check_exists`.
 
 use array::Array;
+use array_buffer::{is_array_buffer, push_array_buffer, ArrayBuffer};
 use bytes::Bytes;
 use error::{Error, Result};
 use ffi;
 use function::{create_callback, Function, Invocation};
+use modules::{self, ModuleSource};
 use object::Object;
+#[cfg(feature = "serde")] use serde_value::DeserializeOptions;
 use std::any::Any;
 use std::cell::RefCell;
+use std::mem;
+use std::slice;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use stdlib::{self, StdlibConfig};
 use string::String;
+use symbol::Symbol;
 use types::Ref;
+use typed_array::{push_typed_array, typed_array_kind_of, TypedArray, TypedArrayKind};
+use user_data::{is_user_data, push_user_data, AnyUserData};
 use util::{
     create_heap,
     get_any_map,
@@ -77,6 +89,122 @@ impl Ducc {
         }
     }
 
+    /// Dumps a `Function` previously returned by `Ducc::compile` into Duktape's bytecode
+    /// representation, which can be persisted and later restored with `Ducc::load_bytecode` to
+    /// skip parsing and compiling the source again.
+    ///
+    /// The resulting bytes are only loadable by a build of Duktape with a matching version and
+    /// configuration; loading them elsewhere is detected and reported as an error rather than
+    /// producing undefined behavior.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the function cannot be dumped, which happens if it was
+    /// not compiled from source (for example, a Rust-backed function created by
+    /// `Ducc::create_function`).
+    pub fn dump_bytecode(&self, func: &Function) -> Result<Vec<u8>> {
+        unsafe {
+            assert_stack!(self.ctx, 0, {
+                self.push_ref(&func.0);
+                protect_duktape_closure(self.ctx, 1, 1, |ctx| {
+                    ffi::duk_dump_function(ctx);
+                })?;
+                let bytes = Bytes(self.pop_ref());
+                Ok(bytes.to_vec())
+            })
+        }
+    }
+
+    /// Loads bytecode previously produced by `Ducc::dump_bytecode` back into a callable
+    /// `Function`, equivalent to the `Function` that was originally dumped.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the bytecode is corrupt, truncated, or was dumped by an
+    /// incompatible build of Duktape.
+    pub fn load_bytecode<'ducc>(&'ducc self, bytecode: &[u8]) -> Result<Function<'ducc>> {
+        unsafe {
+            assert_stack!(self.ctx, 0, {
+                push_bytes(self.ctx, bytecode)?;
+                protect_duktape_closure(self.ctx, 1, 1, |ctx| {
+                    ffi::duk_load_function(ctx);
+                })?;
+                Ok(Function(self.pop_ref()))
+            })
+        }
+    }
+
+    /// Encodes a `Value` into a compact, self-describing byte representation using Duktape's
+    /// built-in CBOR codec.
+    ///
+    /// Equivalent to Duktape's `duk_cbor_encode`.
+    pub fn cbor_encode(&self, value: Value) -> Result<Vec<u8>> {
+        unsafe {
+            assert_stack!(self.ctx, 0, {
+                self.push_value(value);
+                protect_duktape_closure(self.ctx, 1, 1, |ctx| {
+                    ffi::duk_cbor_encode(ctx, -1, 0);
+                })?;
+                let bytes = Bytes(self.pop_ref());
+                Ok(bytes.to_vec())
+            })
+        }
+    }
+
+    /// Decodes a CBOR byte representation, as produced by `Ducc::cbor_encode` (or any other CBOR
+    /// encoder), back into a `Value`.
+    ///
+    /// Equivalent to Duktape's `duk_cbor_decode`.
+    pub fn cbor_decode<'ducc>(&'ducc self, bytes: &[u8]) -> Result<Value<'ducc>> {
+        unsafe {
+            assert_stack!(self.ctx, 0, {
+                push_bytes(self.ctx, bytes)?;
+                protect_duktape_closure(self.ctx, 1, 1, |ctx| {
+                    ffi::duk_cbor_decode(ctx, -1, 0);
+                })?;
+                Ok(self.pop_value())
+            })
+        }
+    }
+
+    /// Converts a `Serialize` value into a `Value` by driving a `serde::Serializer` that builds
+    /// plain objects, arrays, and primitives as it goes (structs and maps become objects, enums
+    /// are externally tagged, `None`/unit become `Null`/`Undefined`).
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn to_value_serde<'ducc, T: ::serde::Serialize>(&'ducc self, value: T) -> Result<Value<'ducc>> {
+        value.serialize(::serde_value::Serializer { ducc: self })
+    }
+
+    /// Converts a `Value` into a `DeserializeOwned` value by driving a `serde::Deserializer` that
+    /// walks it (objects via `Object::properties`, arrays via `Array::elements`; numbers and
+    /// strings are coerced with `coerce_number`/`coerce_string`).
+    ///
+    /// Equivalent to `from_value_serde_with(value, DeserializeOptions::default())`.
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn from_value_serde<'ducc, T: ::serde::de::DeserializeOwned>(
+        &'ducc self,
+        value: Value<'ducc>,
+    ) -> Result<T> {
+        self.from_value_serde_with(value, DeserializeOptions::default())
+    }
+
+    /// Like `from_value_serde`, but with `options` controlling how strictly the walk treats values
+    /// it can't otherwise represent (see `DeserializeOptions`).
+    ///
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn from_value_serde_with<'ducc, T: ::serde::de::DeserializeOwned>(
+        &'ducc self,
+        value: Value<'ducc>,
+        options: DeserializeOptions,
+    ) -> Result<T> {
+        T::deserialize(::serde_value::Deserializer { ducc: self, value, options })
+    }
+
     /// Executes a chunk of JavaScript code and returns its result.
     ///
     /// This is equivalent to calling `Ducc::compile` and `Function::call` immediately after. The
@@ -100,6 +228,81 @@ impl Ducc {
         result.into()
     }
 
+    /// Returns a cloneable handle that can signal cancellation of any script currently running (or
+    /// subsequently run) on this `Ducc` from another thread.
+    ///
+    /// Unlike `ExecSettings::cancel_fn`, which is a polled predicate baked into a single `exec`
+    /// call, an `InterruptHandle` is checked by the same periodic execution hook and can be held
+    /// onto (e.g. by a watchdog thread) to cancel whichever script happens to be running, without
+    /// committing to a time-based predicate ahead of time.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        unsafe {
+            let udata = get_udata(self.ctx);
+            InterruptHandle((*udata).interrupt_flag())
+        }
+    }
+
+    /// Installs `handler` as a standing interrupt check, polled by the same periodic execution hook
+    /// as `ExecSettings::deadline`/`cancel_fn` and `InterruptHandle`, for every script run on this
+    /// `Ducc` until cleared with `clear_interrupt`. Returning `true` cancels the script currently
+    /// running (or the next one to run).
+    ///
+    /// Unlike `ExecSettings::cancel_fn`, which is scoped to a single `exec` call, `handler` persists
+    /// across calls, and unlike `InterruptHandle`, it can be an arbitrary `FnMut` rather than just an
+    /// `AtomicBool` flag — useful for honoring a shutdown signal or enforcing a host-tracked
+    /// instruction budget.
+    pub fn set_interrupt<F>(&self, handler: F)
+    where
+        F: 'static + FnMut() -> bool,
+    {
+        unsafe {
+            let udata = get_udata(self.ctx);
+            (*udata).set_interrupt(handler);
+        }
+    }
+
+    /// Removes the interrupt handler installed by `set_interrupt`, if any.
+    pub fn clear_interrupt(&self) {
+        unsafe {
+            let udata = get_udata(self.ctx);
+            (*udata).clear_interrupt();
+        }
+    }
+
+    /// Sets a standing ceiling, in bytes, on this heap's total live allocation, unaffected by any
+    /// individual `Ducc::exec` call. Once exceeded, further allocations fail as they would under
+    /// real memory pressure, which is reported to scripts the same way Duktape reports any other
+    /// allocation failure (typically a `RangeError`).
+    ///
+    /// Pass `None` to remove the limit. If `ExecSettings::memory_limit` is also set for the
+    /// currently running execution, the tighter of the two is enforced.
+    ///
+    /// Requires the `memory_limit` feature; without it, the heap never tracks allocations, so the
+    /// limit set here is never enforced.
+    pub fn set_memory_limit(&self, limit: Option<usize>) {
+        unsafe {
+            let udata = get_udata(self.ctx);
+            (*udata).set_persistent_memory_limit(limit);
+        }
+    }
+
+    /// Returns the number of bytes currently live on this heap, as tracked by the allocator
+    /// callbacks passed to `duk_create_heap`.
+    ///
+    /// Requires the `memory_limit` feature; without it, the heap doesn't track allocations, and
+    /// this always returns `0`.
+    pub fn memory_usage(&self) -> usize {
+        unsafe { (*get_udata(self.ctx)).bytes_allocated() }
+    }
+
+    /// Returns the largest `memory_usage` has ever reported for this heap.
+    ///
+    /// Requires the `memory_limit` feature; without it, the heap doesn't track allocations, and
+    /// this always returns `0`.
+    pub fn peak_memory_usage(&self) -> usize {
+        unsafe { (*get_udata(self.ctx)).peak_bytes_allocated() }
+    }
+
     /// Inserts any sort of keyed value of type `T` into the `Ducc`, typically for later retrieval
     /// from within Rust functions called from within JavaScript. If a value already exists with the
     /// key, it is returned.
@@ -171,6 +374,56 @@ impl Ducc {
         })
     }
 
+    /// Boxes an arbitrary Rust value of type `T`, and returns a handle to it as a JavaScript
+    /// object. The value is dropped once Duktape's garbage collector finalizes the underlying
+    /// object, exactly as with the boxed closures behind `create_function`. The original `T` can
+    /// be recovered with `AnyUserData::borrow`/`AnyUserData::borrow_mut`.
+    pub fn create_user_data<T: 'static>(&self, value: T) -> AnyUserData {
+        unsafe {
+            assert_stack!(self.ctx, 0, {
+                push_user_data(self.ctx, value);
+                AnyUserData(self.pop_ref())
+            })
+        }
+    }
+
+    /// Creates a new, unique `Symbol`, optionally carrying `description` as its `description`
+    /// property. This is equivalent to calling the JavaScript `Symbol(description)` function.
+    pub fn create_symbol<'ducc>(&'ducc self, description: Option<&str>) -> Result<Symbol<'ducc>> {
+        let symbol_ctor: Function = self.globals().get("Symbol")?;
+        let value: Value = match description {
+            Some(description) => symbol_ctor.call((description,))?,
+            None => symbol_ctor.call(())?,
+        };
+        match value {
+            Value::Symbol(symbol) => Ok(symbol),
+            value => Err(Error::from_js_conversion(value.type_name(), "Symbol")),
+        }
+    }
+
+    /// Returns the well-known `Symbol.iterator`, used to customize an object's default iteration
+    /// behavior (e.g. with `for...of`).
+    pub fn symbol_iterator<'ducc>(&'ducc self) -> Result<Symbol<'ducc>> {
+        self.well_known_symbol("iterator")
+    }
+
+    /// Returns the well-known `Symbol.asyncIterator`, used to customize an object's default async
+    /// iteration behavior (e.g. with `for await...of`).
+    pub fn symbol_async_iterator<'ducc>(&'ducc self) -> Result<Symbol<'ducc>> {
+        self.well_known_symbol("asyncIterator")
+    }
+
+    /// Returns the well-known `Symbol.toStringTag`, used to customize an object's
+    /// `Object.prototype.toString` tag.
+    pub fn symbol_to_string_tag<'ducc>(&'ducc self) -> Result<Symbol<'ducc>> {
+        self.well_known_symbol("toStringTag")
+    }
+
+    fn well_known_symbol<'ducc>(&'ducc self, name: &str) -> Result<Symbol<'ducc>> {
+        let symbol_ctor: Function = self.globals().get("Symbol")?;
+        symbol_ctor.into_object().get(name)
+    }
+
     /// Pass a `&str` to Duktape, creating and returning an interned string.
     pub fn create_string(&self, value: &str) -> Result<String> {
         unsafe {
@@ -191,6 +444,34 @@ impl Ducc {
         }
     }
 
+    /// Creates and returns a `TypedArray` of the given `kind`, backed by a freshly allocated
+    /// buffer containing a copy of `elements`.
+    pub fn create_typed_array<T: Copy>(&self, kind: TypedArrayKind, elements: &[T]) -> Result<TypedArray> {
+        assert_eq!(mem::size_of::<T>(), kind.element_size(), "element type does not match `kind`");
+
+        let bytes = unsafe {
+            slice::from_raw_parts(elements.as_ptr() as *const u8, mem::size_of_val(elements))
+        };
+
+        unsafe {
+            assert_stack!(self.ctx, 0, {
+                push_typed_array(self.ctx, kind, bytes)?;
+                Ok(TypedArray::new(self.pop_ref(), kind))
+            })
+        }
+    }
+
+    /// Creates and returns an `ArrayBuffer` of `len` zeroed bytes, suitable for use as the backing
+    /// store of typed array views constructed from script (for example, `new Uint8Array(buffer)`).
+    pub fn create_array_buffer(&self, len: usize) -> Result<ArrayBuffer> {
+        unsafe {
+            assert_stack!(self.ctx, 0, {
+                push_array_buffer(self.ctx, len)?;
+                Ok(ArrayBuffer(self.pop_ref()))
+            })
+        }
+    }
+
     /// Creates and returns an empty `Object` managed by Duktape.
     pub fn create_object(&self) -> Object {
         unsafe {
@@ -213,6 +494,31 @@ impl Ducc {
         }
     }
 
+    /// Creates and returns an `Array` managed by Duktape filled with the values from an iterator.
+    /// This is equivalent to `create_array()` followed by `Array::extend`, writing every element in
+    /// a single protected Duktape frame regardless of how many elements `iter` yields.
+    pub fn create_array_from<'ducc, V, I>(&'ducc self, iter: I) -> Result<Array<'ducc>>
+    where
+        V: ToValue<'ducc>,
+        I: IntoIterator<Item = V>,
+    {
+        let array = self.create_array();
+        array.extend(iter)?;
+        Ok(array)
+    }
+
+    /// Creates and returns an `Array` managed by Duktape of length `len`, whose element at index
+    /// `i` is `f(i)`. Inspired by `core::array::from_fn`, but for a dynamically-sized JavaScript
+    /// array; like `create_array_from`, every element is written in a single protected Duktape
+    /// frame.
+    pub fn create_array_from_fn<'ducc, V, F>(&'ducc self, len: u32, mut f: F) -> Result<Array<'ducc>>
+    where
+        V: ToValue<'ducc>,
+        F: FnMut(u32) -> V,
+    {
+        self.create_array_from((0..len).map(|i| f(i)))
+    }
+
     /// Creates and returns an `Object` managed by Duktape filled with the keys and values from an
     /// iterator. Keys are coerced to object properties.
     ///
@@ -231,6 +537,33 @@ impl Ducc {
         Ok(object)
     }
 
+    /// Installs the standard-library shims selected by `config` (for example, `console` or
+    /// `btoa`/`atob`) as globals, giving scripts a minimal browser/Node-ish environment to run
+    /// against.
+    ///
+    /// Nothing is installed by default; an unconfigured `StdlibConfig::new()` is a no-op. This
+    /// keeps the cost of shims a script doesn't need off of every heap.
+    pub fn load_stdlib(&self, config: StdlibConfig) -> Result<()> {
+        stdlib::load(self, config)
+    }
+
+    /// Registers `resolver` as this `Ducc`'s module resolver and installs a `require` global backed
+    /// by it, enabling CommonJS-style `require(id)` calls from scripts.
+    ///
+    /// `resolver` is called with the requesting script's own module id (or `None` if the `require`
+    /// call is not itself happening inside a loaded module) and is expected to return either the
+    /// source text of the module to compile and run, or a ready-made `Object` to use as its exports
+    /// directly (for native Rust-backed modules). Each id is only ever resolved once per `Ducc`; its
+    /// exports are cached and reused for subsequent `require` calls with the same id.
+    ///
+    /// Calling `require` before a resolver has been registered is an error.
+    pub fn set_module_resolver<F>(&mut self, resolver: F) -> Result<()>
+    where
+        F: 'static + Send + for<'ducc> Fn(&'ducc Ducc, &str, Option<&str>) -> Result<ModuleSource<'ducc>>,
+    {
+        modules::set_resolver(self, resolver)
+    }
+
     /// Coerces a Duktape value to a string. Nearly all JavaScript values are coercible to strings,
     /// but this may fail with a runtime error under extraordinary circumstances (e.g. if the
     /// Ecmascript `ToString` implementation throws an error).
@@ -274,6 +607,126 @@ impl Ducc {
         }
     }
 
+    /// Parses a JSON string into a `Value`, using Duktape's native `JSON.parse` implementation
+    /// rather than building the result through the `Value` tree field by field.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `json` is not valid JSON.
+    pub fn decode_json<'ducc>(&'ducc self, json: &str) -> Result<Value<'ducc>> {
+        unsafe {
+            assert_stack!(self.ctx, 0, {
+                push_str(self.ctx, json)?;
+                protect_duktape_closure(self.ctx, 1, 1, |ctx| {
+                    ffi::duk_json_decode(ctx, -1);
+                })?;
+                Ok(self.pop_value())
+            })
+        }
+    }
+
+    /// Serializes a `Value` to a JSON string, using Duktape's native `JSON.stringify`
+    /// implementation rather than walking the `Value` tree from Rust.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `value` contains a cycle, or any other value that cannot
+    /// be represented in JSON (e.g. a `Function`).
+    pub fn encode_json<'ducc>(&'ducc self, value: Value<'ducc>) -> Result<String<'ducc>> {
+        unsafe {
+            assert_stack!(self.ctx, 0, {
+                self.push_value(value);
+                protect_duktape_closure(self.ctx, 1, 1, |ctx| {
+                    ffi::duk_json_encode(ctx, -1);
+                })?;
+                Ok(String(self.pop_ref()))
+            })
+        }
+    }
+
+    /// Evaluates an ECMAScript binary operator (`+`, `-`, `<`, etc.) on two values using the
+    /// engine's own abstract operations (`ToPrimitive`, `ToNumber`, `ToString`), without hand-
+    /// running a script through `exec`.
+    ///
+    /// Each operator's evaluator function is compiled once per `Ducc` and cached in the heap
+    /// stash, so repeated calls (even with different operands) only pay the parse/compile cost the
+    /// first time a given `op` is used.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if evaluating the operator throws, for example if a
+    /// `valueOf`/`toString` coercion on an `Object` operand throws.
+    pub fn binary_op<'ducc>(
+        &'ducc self,
+        op: BinaryOp,
+        a: Value<'ducc>,
+        b: Value<'ducc>,
+    ) -> Result<Value<'ducc>> {
+        let cache = self.binary_op_cache();
+        let key = op.as_js_operator();
+        let func: Function = if cache.contains_key(key)? {
+            cache.get(key)?
+        } else {
+            let source = format!("(function(a, b) {{ return a {} b; }})", key);
+            let func: Function = self.compile(&source, None)?.call(())?;
+            cache.set(key, func.clone())?;
+            func
+        };
+        func.call((a, b))
+    }
+
+    // Returns the `Object` used to cache compiled `binary_op` evaluator functions by operator,
+    // creating it in the heap stash the first time it's needed.
+    fn binary_op_cache<'ducc>(&'ducc self) -> Object<'ducc> {
+        const BINARY_OP_CACHE_KEY: [i8; 12] = hidden_i8str!('b', 'i', 'n', 'o', 'p', 'c', 'a', 'c', 'h', 'e');
+        unsafe {
+            assert_stack!(self.ctx, 0, {
+                ffi::duk_require_stack(self.ctx, 2);
+                ffi::duk_push_heap_stash(self.ctx);
+                if ffi::duk_has_prop_string(self.ctx, -1, BINARY_OP_CACHE_KEY.as_ptr()) == 0 {
+                    ffi::duk_push_object(self.ctx);
+                    ffi::duk_put_prop_string(self.ctx, -2, BINARY_OP_CACHE_KEY.as_ptr());
+                }
+                ffi::duk_get_prop_string(self.ctx, -1, BINARY_OP_CACHE_KEY.as_ptr());
+                ffi::duk_remove(self.ctx, -2);
+                Object(self.pop_ref())
+            })
+        }
+    }
+
+    /// Returns `true` if `a` and `b` are equal under JavaScript's strict equality (`===`): no
+    /// type coercion is performed, `NaN` never equals itself, and `+0`/`-0` are equal.
+    pub fn strict_equals<'ducc>(&'ducc self, a: &Value<'ducc>, b: &Value<'ducc>) -> bool {
+        unsafe {
+            assert_stack!(self.ctx, 0, {
+                self.push_value(a.clone());
+                self.push_value(b.clone());
+                let result = ffi::duk_strict_equals(self.ctx, -2, -1) != 0;
+                ffi::duk_pop_2(self.ctx);
+                result
+            })
+        }
+    }
+
+    /// Returns `true` if `a` and `b` are equal under JavaScript's abstract equality (`==`),
+    /// applying the standard coercion rules between `null`/`undefined` and numbers/strings/
+    /// objects.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the coercion required to compare the two values throws.
+    pub fn abstract_equals<'ducc>(&'ducc self, a: &Value<'ducc>, b: &Value<'ducc>) -> Result<bool> {
+        unsafe {
+            assert_stack!(self.ctx, 0, {
+                self.push_value(a.clone());
+                self.push_value(b.clone());
+                protect_duktape_closure(self.ctx, 2, 0, |ctx| {
+                    ffi::duk_equals(ctx, -2, -1) != 0
+                })
+            })
+        }
+    }
+
     pub(crate) unsafe fn push_value(&self, value: Value) {
         assert_stack!(self.ctx, 1, {
             match value {
@@ -298,6 +751,10 @@ impl Ducc {
                 Value::Array(a) => self.push_ref(&a.0),
                 Value::Object(o) => self.push_ref(&o.0),
                 Value::Bytes(b) => self.push_ref(&b.0),
+                Value::TypedArray(t) => self.push_ref(&t.ref_),
+                Value::ArrayBuffer(a) => self.push_ref(&a.0),
+                Value::UserData(u) => self.push_ref(&u.0),
+                Value::Symbol(s) => self.push_ref(&s.0),
             }
         })
     }
@@ -327,13 +784,27 @@ impl Ducc {
                     Value::Number(result)
                 },
                 ffi::DUK_TYPE_STRING => {
-                    Value::String(String(self.pop_ref()))
+                    if ffi::duk_is_symbol(self.ctx, -1) != 0 {
+                        Value::Symbol(Symbol(self.pop_ref()))
+                    } else {
+                        Value::String(String(self.pop_ref()))
+                    }
                 },
                 ffi::DUK_TYPE_OBJECT => {
                     if ffi::duk_is_function(self.ctx, -1) != 0 {
                         Value::Function(Function(self.pop_ref()))
                     } else if ffi::duk_is_array(self.ctx, -1) != 0 {
                         Value::Array(Array(self.pop_ref()))
+                    } else if ffi::duk_is_buffer_data(self.ctx, -1) != 0 {
+                        match typed_array_kind_of(self.ctx, -1) {
+                            Some(kind) => Value::TypedArray(TypedArray::new(self.pop_ref(), kind)),
+                            None if is_array_buffer(self.ctx, -1) => {
+                                Value::ArrayBuffer(ArrayBuffer(self.pop_ref()))
+                            },
+                            None => Value::Object(Object(self.pop_ref())),
+                        }
+                    } else if is_user_data(self.ctx, -1) {
+                        Value::UserData(AnyUserData(self.pop_ref()))
                     } else {
                         Value::Object(Object(self.pop_ref()))
                     }
@@ -437,4 +908,86 @@ pub struct ExecSettings {
     /// execution timeout. This function is only called during JavaScript execution, and will not be
     /// called while execution is within native Rust code.
     pub cancel_fn: Option<Box<Fn() -> bool>>,
+    /// A wall-clock budget for this execution. Once it elapses, the running script is aborted,
+    /// raising the same runtime error as a `cancel_fn`-driven cancellation or an
+    /// `InterruptHandle::cancel`.
+    pub deadline: Option<Duration>,
+    /// A ceiling, in bytes, on the JavaScript heap's total live allocation. Once exceeded, further
+    /// allocations fail as they would under real memory pressure, which is reported to scripts the
+    /// same way Duktape reports any other allocation failure (typically a `RangeError`).
+    ///
+    /// This bounds the heap's entire footprint, not just what this execution allocates, so it also
+    /// accounts for memory already retained from prior executions on the same `Ducc`. See also
+    /// `Ducc::set_memory_limit`, which sets a standing limit rather than one scoped to a single
+    /// `exec` call; the tighter of the two applies when both are set.
+    ///
+    /// Requires the `memory_limit` feature; without it, the heap never tracks allocations, so this
+    /// limit is never enforced.
+    pub memory_limit: Option<usize>,
+}
+
+impl ExecSettings {
+    /// A convenience constructor for the common case of bounding execution to a wall-clock budget.
+    /// Equivalent to `ExecSettings { deadline: Some(deadline), ..ExecSettings::default() }`.
+    pub fn with_deadline(deadline: Duration) -> ExecSettings {
+        ExecSettings { deadline: Some(deadline), ..ExecSettings::default() }
+    }
+
+    /// A convenience constructor for the common case of bounding execution to a memory budget.
+    /// Equivalent to `ExecSettings { memory_limit: Some(limit), ..ExecSettings::default() }`.
+    pub fn with_memory_limit(limit: usize) -> ExecSettings {
+        ExecSettings { memory_limit: Some(limit), ..ExecSettings::default() }
+    }
+}
+
+/// A cloneable handle that can be used to cancel a running script from any thread.
+///
+/// Obtained via `Ducc::interrupt_handle`. Calling `cancel()` causes the next periodic
+/// execution-timeout check to abort the running script, raising the same runtime error as an
+/// `ExecSettings::cancel_fn`-driven timeout. The flag can be cleared with `reset()` to allow the
+/// handle to be reused for a subsequent script.
+#[derive(Clone)]
+pub struct InterruptHandle(Arc<AtomicBool>);
+
+impl InterruptHandle {
+    /// Signals that the script currently running (or next run) on the originating `Ducc` should be
+    /// cancelled as soon as possible.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Clears a previously signalled cancellation.
+    pub fn reset(&self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+/// An ECMAScript binary operator, usable with `Ducc::binary_op`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+}
+
+impl BinaryOp {
+    fn as_js_operator(self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Mod => "%",
+            BinaryOp::Lt => "<",
+            BinaryOp::Gt => ">",
+            BinaryOp::Le => "<=",
+            BinaryOp::Ge => ">=",
+        }
+    }
 }
@@ -1,4 +1,6 @@
+use ducc::Ducc;
 use ffi;
+use object::Object;
 use std::any::TypeId;
 use std::error::Error as StdError;
 use std::{fmt, result};
@@ -36,6 +38,13 @@ pub enum ErrorKind {
         code: RuntimeErrorCode,
         /// A string representation of the type of error.
         name: String,
+        /// The JavaScript error's `.stack` property, if the linked Duktape build records
+        /// tracebacks. This is a human-readable, multi-line string describing the call stack at
+        /// the point the error was thrown.
+        stack: Option<String>,
+        /// The source location (file name and line number) the error was thrown from, read off
+        /// the JavaScript error's `fileName`/`lineNumber` properties, if present.
+        location: Option<(String, u32)>,
     },
     /// A mutable callback has triggered JavaScript code that has called the same mutable callback
     /// again.
@@ -48,6 +57,12 @@ pub enum ErrorKind {
     ExternalError(Box<dyn RuntimeError + 'static>),
     /// An error specifying the variable that was called as a function was not a function.
     NotAFunction,
+    /// A `PropertyDescriptor` passed to `Object::define_prop` combined attributes that are not
+    /// valid together, e.g. `writable` with a getter/setter source.
+    InvalidPropertyDescriptor,
+    /// A script called `require` before a module resolver was registered with
+    /// `Ducc::set_module_resolver`.
+    NoModuleResolver,
 }
 
 impl Error {
@@ -81,11 +96,55 @@ impl Error {
         Error { kind: ErrorKind::NotAFunction, context: vec![] }
     }
 
+    pub fn invalid_property_descriptor() -> Error {
+        Error { kind: ErrorKind::InvalidPropertyDescriptor, context: vec![] }
+    }
+
+    pub fn no_module_resolver() -> Error {
+        Error { kind: ErrorKind::NoModuleResolver, context: vec![] }
+    }
+
+    /// Attempts to downcast the wrapped external error to a concrete type by reference.
+    ///
+    /// This recurses through the `source()` chain, including any further `Error`s that were
+    /// re-wrapped after crossing back through JavaScript, returning the first concrete `T` found.
+    pub fn downcast_ref<T: RuntimeError + 'static>(&self) -> Option<&T> {
+        if let ErrorKind::ExternalError(ref err) = self.kind {
+            if let Some(err) = err.downcast_ref::<T>() {
+                return Some(err);
+            }
+        }
+
+        let mut source = StdError::source(self);
+        while let Some(err) = source {
+            if let Some(err) = err.downcast_ref::<Error>().and_then(Error::downcast_ref::<T>) {
+                return Some(err);
+            }
+
+            source = err.source();
+        }
+
+        None
+    }
+
+    /// Returns the deepest error in this error's `source()` chain, i.e. the error that has no
+    /// further source of its own.
+    pub fn root_cause(&self) -> &(dyn StdError + 'static) {
+        let mut cause: &(dyn StdError + 'static) = self;
+
+        while let Some(source) = cause.source() {
+            cause = source;
+        }
+
+        cause
+    }
+
     pub(crate) fn into_runtime_error_desc(self) -> RuntimeErrorDesc {
         RuntimeErrorDesc {
             code: self.runtime_code(),
             name: self.runtime_name(),
             message: self.runtime_message(),
+            location: self.runtime_location(),
             cause: Box::new(self),
         }
     }
@@ -95,6 +154,8 @@ impl Error {
             ErrorKind::ToJsConversionError { .. } => RuntimeErrorCode::TypeError,
             ErrorKind::FromJsConversionError { .. } => RuntimeErrorCode::TypeError,
             ErrorKind::NotAFunction => RuntimeErrorCode::TypeError,
+            ErrorKind::InvalidPropertyDescriptor => RuntimeErrorCode::TypeError,
+            ErrorKind::NoModuleResolver => RuntimeErrorCode::ReferenceError,
             ErrorKind::ExternalError(err) => err.code(),
             _ => RuntimeErrorCode::Error
         }
@@ -134,12 +195,31 @@ impl Error {
             None
         }
     }
+
+    // The source location this error should be reported as having been thrown from, if it carries
+    // one. Currently this is only threaded through for a `RuntimeError` that was itself recovered
+    // from a thrown JavaScript error (e.g. re-thrown after crossing back through Rust), so its
+    // original location survives the round trip rather than being reported as coming from wherever
+    // `push_error` happened to run.
+    fn runtime_location(&self) -> Option<(String, u32)> {
+        match self.kind {
+            ErrorKind::RuntimeError { ref location, .. } => location.clone(),
+            _ => None,
+        }
+    }
 }
 
 impl StdError for Error {
     fn description(&self) -> &'static str {
         "JavaScript execution error"
     }
+
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self.kind {
+            ErrorKind::ExternalError(ref err) => err.source(),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -155,13 +235,34 @@ impl fmt::Display for Error {
             ErrorKind::FromJsConversionError { from, to } => {
                 write!(fmt, "error converting JavaScript {} to {}", from, to)
             },
-            ErrorKind::RuntimeError { ref name, .. } => {
-                write!(fmt, "JavaScript runtime error ({})", name)
+            ErrorKind::RuntimeError { ref name, ref location, ref stack, .. } => {
+                write!(fmt, "JavaScript runtime error ({})", name)?;
+                if let Some((ref file_name, line_number)) = *location {
+                    write!(fmt, " at {}:{}", file_name, line_number)?;
+                }
+                if let Some(ref stack) = *stack {
+                    write!(fmt, "\n{}", stack)?;
+                }
+                Ok(())
             },
             ErrorKind::RecursiveMutCallback => write!(fmt, "mutable callback called recursively"),
             ErrorKind::NotAFunction => write!(fmt, "tried to a call a non-function"),
+            ErrorKind::InvalidPropertyDescriptor => write!(fmt, "invalid property descriptor"),
+            ErrorKind::NoModuleResolver => {
+                write!(fmt, "require() called without a module resolver registered")
+            },
             ErrorKind::ExternalError(ref err) => err.fmt(fmt),
+        }?;
+
+        if cfg!(feature = "display-cause") {
+            let mut source = StdError::source(self);
+            while let Some(err) = source {
+                write!(fmt, ": {}", err)?;
+                source = err.source();
+            }
         }
+
+        Ok(())
     }
 }
 
@@ -210,6 +311,7 @@ pub(crate) struct RuntimeErrorDesc {
     pub code: RuntimeErrorCode,
     pub name: String,
     pub message: Option<String>,
+    pub location: Option<(String, u32)>,
     pub cause: Box<Error>,
 }
 
@@ -224,6 +326,10 @@ pub enum RuntimeErrorCode {
     SyntaxError,
     TypeError,
     UriError,
+    /// A custom, non-standard error class name (for example, a domain-specific `ValidationError`).
+    /// The underlying JavaScript error still uses the generic `Error` prototype/`duk_errcode_t`,
+    /// since Duktape has no concept of user-defined error codes.
+    Custom(String),
 }
 
 impl RuntimeErrorCode {
@@ -249,6 +355,7 @@ impl RuntimeErrorCode {
             RuntimeErrorCode::SyntaxError => ffi::DUK_ERR_SYNTAX_ERROR,
             RuntimeErrorCode::TypeError => ffi::DUK_ERR_TYPE_ERROR,
             RuntimeErrorCode::UriError => ffi::DUK_ERR_URI_ERROR,
+            RuntimeErrorCode::Custom(_) => ffi::DUK_ERR_ERROR,
         }) as ffi::duk_errcode_t
     }
 }
@@ -263,6 +370,7 @@ impl fmt::Display for RuntimeErrorCode {
             RuntimeErrorCode::SyntaxError => write!(f, "SyntaxError"),
             RuntimeErrorCode::TypeError => write!(f, "TypeError"),
             RuntimeErrorCode::UriError => write!(f, "URIError"),
+            RuntimeErrorCode::Custom(ref name) => write!(f, "{}", name),
         }
     }
 }
@@ -292,11 +400,24 @@ pub trait RuntimeError: fmt::Debug {
         None
     }
 
-    // TODO: Should we support modifying the error object?
-    // fn customize<'ducc>(&self, ducc: &'ducc Ducc, object: &'ducc Object<'ducc>) {
-    //     let _ = ducc;
-    //     let _ = object;
-    // }
+    /// An optional underlying cause of this error. When set, this is returned by the parent
+    /// `Error`'s `std::error::Error::source` implementation, allowing callers to walk the full
+    /// chain of causes that crossed the JS/Rust boundary.
+    ///
+    /// By default, this method returns `None`.
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        None
+    }
+
+    /// Called after the JavaScript error object has been constructed, allowing implementors to set
+    /// arbitrary own-properties on it (an error code, an `errno`, structured diagnostic data, and so
+    /// on) that scripts can inspect on the thrown value.
+    ///
+    /// By default, this method does nothing.
+    fn customize<'ducc>(&self, ducc: &'ducc Ducc, object: &Object<'ducc>) {
+        let _ = ducc;
+        let _ = object;
+    }
 
     #[doc(hidden)]
     fn __private_get_type_id__(&self) -> TypeId where Self: 'static {
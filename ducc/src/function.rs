@@ -1,10 +1,11 @@
+use bytes::Bytes;
 use ducc::Ducc;
 use error::Result;
 use ffi;
 use object::Object;
 use std::panic::{AssertUnwindSafe, catch_unwind};
 use types::{Callback, Ref};
-use util::{pop_error, push_error};
+use util::{pop_error, protect_duktape_closure, push_error};
 use value::{FromValue, ToValue, ToValues, Value, Values};
 
 /// Reference to a JavaScript function.
@@ -83,6 +84,31 @@ impl<'ducc> Function<'ducc> {
     pub fn into_object(self) -> Object<'ducc> {
         Object(self.0)
     }
+
+    /// Dumps this function (previously returned by `Ducc::compile`) into Duktape's bytecode
+    /// representation, which can be persisted and later restored with `Ducc::load_bytecode` to
+    /// skip parsing and compiling the source again.
+    ///
+    /// This is a convenience wrapper equivalent to `Ducc::dump_bytecode`, returning the result as
+    /// a zero-copy `Bytes` rather than a `Vec<u8>`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the function cannot be dumped, which happens if it was
+    /// not compiled from source (for example, a Rust-backed function created by
+    /// `Ducc::create_function`).
+    pub fn dump_bytecode(&self) -> Result<Bytes<'ducc>> {
+        let ducc = self.0.ducc;
+        unsafe {
+            assert_stack!(ducc.ctx, 0, {
+                ducc.push_ref(&self.0);
+                protect_duktape_closure(ducc.ctx, 1, 1, |ctx| {
+                    ffi::duk_dump_function(ctx);
+                })?;
+                Ok(Bytes(ducc.pop_ref()))
+            })
+        }
+    }
 }
 
 pub struct Invocation<'ducc> {
@@ -132,7 +158,7 @@ pub(crate) fn create_callback<'ducc, 'callback>(
                     1
                 },
                 Err(error) => {
-                    push_error(ctx, error);
+                    push_error(&ducc, error);
                     -1
                 },
             }
@@ -1,25 +1,43 @@
 extern crate cesu8;
 extern crate ducc_sys as ffi;
+#[cfg(feature = "ndarray")] extern crate ndarray;
+#[cfg(feature = "serde")] #[macro_use] extern crate serde;
+#[cfg(all(test, feature = "serde"))] #[macro_use] extern crate serde_derive;
 
 #[macro_use] mod util;
 mod array;
+mod array_buffer;
 mod bytes;
 mod conversion;
 mod ducc;
 mod error;
 mod function;
+mod modules;
+#[cfg(feature = "ndarray")] mod ndarray_value;
 mod object;
+#[cfg(feature = "serde")] mod serde_value;
+mod stdlib;
 mod string;
+mod symbol;
 mod types;
+mod typed_array;
+mod user_data;
 mod value;
 
 #[cfg(test)] mod tests;
 
 pub use array::{Array, Elements};
+pub use array_buffer::ArrayBuffer;
 pub use bytes::Bytes;
-pub use ducc::{Ducc, ExecSettings};
+pub use ducc::{BinaryOp, Ducc, ExecSettings, InterruptHandle};
 pub use error::{Error, ErrorKind, Result, ResultExt, RuntimeError, RuntimeErrorCode};
 pub use function::{Function, Invocation};
-pub use object::{Object, Properties};
+pub use modules::ModuleSource;
+pub use object::{EnumOptions, Object, Properties, PropertyDescriptor};
+#[cfg(feature = "serde")] pub use serde_value::DeserializeOptions;
+pub use stdlib::{ConsoleLevel, StdlibConfig};
 pub use string::String;
+pub use symbol::Symbol;
+pub use typed_array::{TypedArray, TypedArrayKind, TypedSlice};
+pub use user_data::AnyUserData;
 pub use value::{FromValue, FromValues, ToValue, ToValues, Value, Values, Variadic};
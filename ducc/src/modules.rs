@@ -0,0 +1,99 @@
+// CommonJS-style module loading. `Ducc::set_module_resolver` registers a Rust callback and
+// installs a `require` global backed by it; each resolved module's exports are cached in the heap
+// stash keyed by id, so a given id is only ever resolved (and its source, if any, only ever
+// executed) once per `Ducc`.
+
+use ducc::Ducc;
+use error::{Error, Result};
+use ffi;
+use function::Function;
+use object::Object;
+use std::string::String as StdString;
+use value::Value;
+
+/// What a module resolver returns for a requested module id: either JS source text to compile and
+/// run as the module's body (with `module`, `exports`, and `require` in scope, exactly as Node's
+/// module wrapper provides them), or a pre-built `Object` to use directly as the module's exports,
+/// for native Rust-backed modules.
+pub enum ModuleSource<'ducc> {
+    Source(StdString),
+    Exports(Object<'ducc>),
+}
+
+pub(crate) struct ModuleResolver(
+    Box<dyn for<'ducc> Fn(&'ducc Ducc, &str, Option<&str>) -> Result<ModuleSource<'ducc>> + Send>,
+);
+
+const MODULE_RESOLVER_KEY: &str = "ducc_module_resolver";
+const MODULE_CACHE_KEY: [i8; 9] = hidden_i8str!('m', 'o', 'd', 'u', 'l', 'e', 's');
+
+pub(crate) fn set_resolver<F>(ducc: &mut Ducc, resolver: F) -> Result<()>
+where
+    F: 'static + Send + for<'ducc2> Fn(&'ducc2 Ducc, &str, Option<&str>) -> Result<ModuleSource<'ducc2>>,
+{
+    ducc.set_user_data(MODULE_RESOLVER_KEY, ModuleResolver(Box::new(resolver)));
+    let require = make_require(ducc, None);
+    ducc.globals().set("require", require)
+}
+
+fn make_require<'ducc>(ducc: &'ducc Ducc, requiring_id: Option<StdString>) -> Function<'ducc> {
+    ducc.create_function(move |inv| -> Result<Value> {
+        let id: StdString = inv.args.from(inv.ducc, 0)?;
+        resolve(inv.ducc, &id, requiring_id.as_ref().map(StdString::as_str))
+    })
+}
+
+fn resolve<'ducc>(ducc: &'ducc Ducc, id: &str, requiring_id: Option<&str>) -> Result<Value<'ducc>> {
+    let cache = module_cache(ducc);
+    if cache.contains_key(id)? {
+        return cache.get(id);
+    }
+
+    let resolver = ducc.get_user_data::<ModuleResolver>(MODULE_RESOLVER_KEY)
+        .ok_or_else(Error::no_module_resolver)?;
+    let source = (resolver.0)(ducc, id, requiring_id)?;
+
+    let exports = match source {
+        ModuleSource::Source(source) => {
+            let wrapped = format!("(function(module, exports, require) {{\n{}\n}})", source);
+            let wrapper: Function = ducc.compile(&wrapped, Some(id))?.call(())?;
+
+            let module = ducc.create_object();
+            let exports = ducc.create_object();
+            module.set("exports", exports.clone())?;
+
+            // Cache the initial `module.exports` before running the module body, exactly as Node
+            // does: a circular `require` (A requires B, B requires A) hits this cache entry and
+            // sees the same partially-populated exports object, rather than re-resolving `id` and
+            // recursing until Duktape's stack guard aborts. The cache is updated again below with
+            // the final value in case the module replaced `module.exports` outright.
+            cache.set(id, exports.clone())?;
+
+            let require = make_require(ducc, Some(id.to_string()));
+            wrapper.call::<_, ()>((module.clone(), exports, require))?;
+            module.get("exports")?
+        },
+        ModuleSource::Exports(exports) => Value::Object(exports),
+    };
+
+    cache.set(id, exports.clone())?;
+    Ok(exports)
+}
+
+// Returns the `Object` used to cache resolved modules by id, creating it in the heap stash the
+// first time it's needed.
+fn module_cache<'ducc>(ducc: &'ducc Ducc) -> Object<'ducc> {
+    unsafe {
+        assert_stack!(ducc.ctx, 0, {
+            ffi::duk_require_stack(ducc.ctx, 2);
+            ffi::duk_push_heap_stash(ducc.ctx);
+            if ffi::duk_has_prop_string(ducc.ctx, -1, MODULE_CACHE_KEY.as_ptr()) == 0 {
+                ffi::duk_push_object(ducc.ctx);
+                ffi::duk_put_prop_string(ducc.ctx, -2, MODULE_CACHE_KEY.as_ptr());
+            }
+            ffi::duk_get_prop_string(ducc.ctx, -1, MODULE_CACHE_KEY.as_ptr());
+            ffi::duk_remove(ducc.ctx, -2);
+            Object(ducc.pop_ref())
+        })
+    }
+}
@@ -0,0 +1,67 @@
+// Optional ndarray bridge: `ToValue`/`FromValue` for `ndarray::ArrayD<T>`, mapping an N-dimensional
+// array to a flat typed array (holding a copy of the elements) plus a `shape`/`strides` descriptor object (rather than
+// nested JS arrays), and reconstructing the `ArrayD` from those descriptors on the way back. Only
+// compiled when the `ndarray` feature is enabled.
+
+use ducc::Ducc;
+use error::{Error, Result};
+use ndarray::{ArrayD, IxDyn};
+use typed_array::{TypedArrayKind, TypedSlice};
+use value::{FromValue, ToValue, Value};
+
+// Computes the strides of a standard (row-major/C order) layout for `shape`, i.e. the strides
+// `ndarray::ArrayD::from_shape_vec` itself assumes for a plain `Vec` with no explicit ordering.
+fn row_major_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1];
+    }
+    strides
+}
+
+macro_rules! convert_ndarray {
+    ($prim_ty: ty, $kind: ident) => {
+        impl<'ducc> ToValue<'ducc> for ArrayD<$prim_ty> {
+            fn to_value(self, ducc: &'ducc Ducc) -> Result<Value<'ducc>> {
+                let shape = self.shape().to_vec();
+                let strides = row_major_strides(&shape);
+                let data: Vec<$prim_ty> = self.iter().cloned().collect();
+
+                let object = ducc.create_object();
+                object.set("shape", shape)?;
+                object.set("strides", strides)?;
+                object.set("data", ducc.create_typed_array(TypedArrayKind::$kind, &data)?)?;
+                Ok(Value::Object(object))
+            }
+        }
+
+        impl<'ducc> FromValue<'ducc> for ArrayD<$prim_ty> {
+            fn from_value(value: Value<'ducc>, ducc: &'ducc Ducc) -> Result<Self> {
+                let object = match value {
+                    Value::Object(object) => object,
+                    value => return Err(Error::from_js_conversion(value.type_name(), "ArrayD")),
+                };
+
+                let shape: Vec<usize> = object.get("shape")?;
+                let strides: Vec<usize> = object.get("strides")?;
+                let data = TypedSlice::<$prim_ty>::from_value(object.get("data")?, ducc)?.into_vec();
+
+                if strides != row_major_strides(&shape) {
+                    return Err(Error::from_js_conversion("object", "ArrayD"));
+                }
+
+                ArrayD::from_shape_vec(IxDyn(&shape), data)
+                    .map_err(|_| Error::from_js_conversion("object", "ArrayD"))
+            }
+        }
+    }
+}
+
+convert_ndarray!(i8, Int8);
+convert_ndarray!(u8, Uint8);
+convert_ndarray!(i16, Int16);
+convert_ndarray!(u16, Uint16);
+convert_ndarray!(i32, Int32);
+convert_ndarray!(u32, Uint32);
+convert_ndarray!(f32, Float32);
+convert_ndarray!(f64, Float64);
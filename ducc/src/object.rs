@@ -1,4 +1,4 @@
-use error::{Error, Result};
+use error::{Error, Result, ResultExt};
 use ffi;
 use std::marker::PhantomData;
 use types::Ref;
@@ -75,6 +75,10 @@ impl<'ducc> Object<'ducc> {
         let ducc = self.0.ducc;
         let key = key.to_value(ducc)?;
 
+        if desc.writable.is_some() && desc.is_accessor_descriptor() {
+            return Err(Error::invalid_property_descriptor().js_err_context("invalid descriptor"));
+        }
+
         let mut flags = 0;
         flags |= match desc.writable {
             Some(true) => ffi::DUK_DEFPROP_HAVE_WRITABLE | ffi::DUK_DEFPROP_WRITABLE,
@@ -206,16 +210,79 @@ impl<'ducc> Object<'ducc> {
         }
     }
 
+    /// Returns the own property descriptor for `key`, or `None` if the object has no own property
+    /// with that key. This is equivalent to calling `Object.getOwnPropertyDescriptor(self, key)`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if:
+    ///
+    /// * `ToValue::to_value` fails for the key
+    /// * The `ToPropertyKey` implementation for the key fails
+    pub fn get_own_property_descriptor<K: ToValue<'ducc>>(
+        &self,
+        key: K,
+    ) -> Result<Option<PropertyDescriptor<'ducc>>> {
+        let ducc = self.0.ducc;
+        let key = key.to_value(ducc)?;
+
+        let object_ctor: Object = ducc.globals().get("Object")?;
+        let result: Value =
+            object_ctor.call_prop("getOwnPropertyDescriptor", (Value::Object(self.clone()), key))?;
+
+        let desc = match result {
+            Value::Object(desc) => desc,
+            _ => return Ok(None),
+        };
+
+        let enumerable = desc.get::<_, bool>("enumerable")?;
+        let configurable = desc.get::<_, bool>("configurable")?;
+
+        let (writable, source) = if desc.contains_key("value")? {
+            let value: Value = desc.get("value")?;
+            let writable = desc.get::<_, bool>("writable")?;
+            (Some(writable), PropertySource::Value(value))
+        } else {
+            let get: Value = desc.get("get")?;
+            let set: Value = desc.get("set")?;
+            let source = match (get.as_function().cloned(), set.as_function().cloned()) {
+                (Some(get), Some(set)) => PropertySource::GetSet(get, set),
+                (Some(get), None) => PropertySource::Get(get),
+                (None, Some(set)) => PropertySource::Set(set),
+                (None, None) => PropertySource::Undefined,
+            };
+            (None, source)
+        };
+
+        Ok(Some(PropertyDescriptor {
+            enumerable: Some(enumerable),
+            configurable: Some(configurable),
+            writable,
+            source,
+        }))
+    }
+
     /// Returns an iterator over the object's keys and values, acting like a `for-in` loop: own and
     /// inherited enumerable properties are included, and enumeration order follows the ES2015
     /// `OwnPropertyKeys` enumeration order, applied for each inheritance level.
+    ///
+    /// This is a shortcut for `properties_with(EnumOptions::new())`.
     pub fn properties<K: FromValue<'ducc>, V: FromValue<'ducc>>(self) -> Properties<'ducc, K, V> {
+        self.properties_with(EnumOptions::new())
+    }
+
+    /// Returns an iterator over the object's keys and values, with the enumeration mode controlled
+    /// by `opts`.
+    pub fn properties_with<K: FromValue<'ducc>, V: FromValue<'ducc>>(
+        self,
+        opts: EnumOptions,
+    ) -> Properties<'ducc, K, V> {
         let ducc = self.0.ducc;
         unsafe {
             let _sg = StackGuard::new(ducc.ctx);
             ducc.push_ref(&self.0);
             ffi::duk_require_stack(ducc.ctx, 1);
-            ffi::duk_enum(ducc.ctx, -1, 0);
+            ffi::duk_enum(ducc.ctx, -1, opts.flags());
             Properties {
                 object_enum: ducc.pop_ref(),
                 _phantom: PhantomData,
@@ -224,6 +291,95 @@ impl<'ducc> Object<'ducc> {
     }
 }
 
+/// Controls which properties `Object::properties_with` enumerates and in what order, mirroring the
+/// distinctions Duktape's `duk_enum` flags expose.
+///
+/// `EnumOptions::new()` reproduces the plain `for-in` semantics used by `Object::properties`:
+/// inherited, enumerable, string-keyed properties only, in `for-in` order.
+pub struct EnumOptions {
+    own_only: bool,
+    include_nonenumerable: bool,
+    include_symbols: bool,
+    include_hidden: bool,
+    sort_array_indices: bool,
+}
+
+impl EnumOptions {
+    pub fn new() -> EnumOptions {
+        EnumOptions {
+            own_only: false,
+            include_nonenumerable: false,
+            include_symbols: false,
+            include_hidden: false,
+            sort_array_indices: false,
+        }
+    }
+
+    /// Restricts enumeration to the object's own properties, excluding any inherited from its
+    /// prototype chain. Corresponds to `DUK_ENUM_OWN_PROPERTY_ONLY`.
+    ///
+    /// Defaults to `false`.
+    pub fn own_only(mut self, b: bool) -> EnumOptions {
+        self.own_only = b;
+        self
+    }
+
+    /// Includes properties with `[[Enumerable]]` set to `false`. Corresponds to
+    /// `DUK_ENUM_INCLUDE_NONENUMERABLE`.
+    ///
+    /// Defaults to `false`.
+    pub fn include_nonenumerable(mut self, b: bool) -> EnumOptions {
+        self.include_nonenumerable = b;
+        self
+    }
+
+    /// Includes symbol-keyed properties alongside string-keyed ones. Corresponds to
+    /// `DUK_ENUM_INCLUDE_SYMBOLS`.
+    ///
+    /// Defaults to `false`.
+    pub fn include_symbols(mut self, b: bool) -> EnumOptions {
+        self.include_symbols = b;
+        self
+    }
+
+    /// Includes Duktape-internal (hidden) properties. Corresponds to `DUK_ENUM_INCLUDE_HIDDEN`.
+    ///
+    /// Defaults to `false`.
+    pub fn include_hidden(mut self, b: bool) -> EnumOptions {
+        self.include_hidden = b;
+        self
+    }
+
+    /// Sorts array index keys (e.g. `"0"`, `"1"`, `"2"`) numerically ahead of other string keys.
+    /// Corresponds to `DUK_ENUM_SORT_ARRAY_INDICES`.
+    ///
+    /// Defaults to `false`.
+    pub fn sort_array_indices(mut self, b: bool) -> EnumOptions {
+        self.sort_array_indices = b;
+        self
+    }
+
+    fn flags(&self) -> ffi::duk_uint_t {
+        let mut flags = 0;
+        if self.own_only {
+            flags |= ffi::DUK_ENUM_OWN_PROPERTY_ONLY;
+        }
+        if self.include_nonenumerable {
+            flags |= ffi::DUK_ENUM_INCLUDE_NONENUMERABLE;
+        }
+        if self.include_symbols {
+            flags |= ffi::DUK_ENUM_INCLUDE_SYMBOLS;
+        }
+        if self.include_hidden {
+            flags |= ffi::DUK_ENUM_INCLUDE_HIDDEN;
+        }
+        if self.sort_array_indices {
+            flags |= ffi::DUK_ENUM_SORT_ARRAY_INDICES;
+        }
+        flags
+    }
+}
+
 enum PropertySource<'ducc> {
     Undefined,
     Value(Value<'ducc>),
@@ -248,6 +404,27 @@ impl <'ducc> PropertyDescriptor<'ducc> {
         }
     }
 
+    /// Creates a data descriptor carrying `value`. Equivalent to
+    /// `PropertyDescriptor::new().value(value)`, but makes the descriptor's flavor explicit.
+    pub fn data(value: Value<'ducc>) -> PropertyDescriptor<'ducc> {
+        PropertyDescriptor::new().value(value)
+    }
+
+    /// Creates an accessor descriptor carrying `get` and/or `set`. Pass `None` for whichever half
+    /// is absent.
+    pub fn accessor(
+        get: Option<Function<'ducc>>,
+        set: Option<Function<'ducc>>,
+    ) -> PropertyDescriptor<'ducc> {
+        let source = match (get, set) {
+            (Some(get), Some(set)) => PropertySource::GetSet(get, set),
+            (Some(get), None) => PropertySource::Get(get),
+            (None, Some(set)) => PropertySource::Set(set),
+            (None, None) => PropertySource::Undefined,
+        };
+        PropertyDescriptor { source, ..PropertyDescriptor::new() }
+    }
+
     /// Whether this property shows up during enumeration of the
     /// properties on the corresponding object.
     /// 
@@ -298,6 +475,65 @@ impl <'ducc> PropertyDescriptor<'ducc> {
         self.source = PropertySource::Set(set);
         self
     }
+
+    /// Returns whether this descriptor's `[[Enumerable]]` attribute is set. Returns `false` if the
+    /// attribute was never specified.
+    pub fn is_enumerable(&self) -> bool {
+        self.enumerable.unwrap_or(false)
+    }
+
+    /// Returns whether this descriptor's `[[Configurable]]` attribute is set. Returns `false` if
+    /// the attribute was never specified.
+    pub fn is_configurable(&self) -> bool {
+        self.configurable.unwrap_or(false)
+    }
+
+    /// Returns whether this descriptor's `[[Writable]]` attribute is set. Returns `false` if the
+    /// attribute was never specified, or if this is an accessor descriptor.
+    pub fn is_writable(&self) -> bool {
+        !self.is_accessor_descriptor() && self.writable.unwrap_or(false)
+    }
+
+    /// Returns `true` if this is a data descriptor, i.e. one carrying a `[[Value]]`.
+    pub fn is_data_descriptor(&self) -> bool {
+        match self.source {
+            PropertySource::Value(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if this is an accessor descriptor, i.e. one carrying a `[[Get]]` and/or
+    /// `[[Set]]`.
+    pub fn is_accessor_descriptor(&self) -> bool {
+        match self.source {
+            PropertySource::GetSet(..) | PropertySource::Get(_) | PropertySource::Set(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns this data descriptor's `[[Value]]`, or `None` if this is not a data descriptor.
+    pub fn as_value(&self) -> Option<&Value<'ducc>> {
+        match self.source {
+            PropertySource::Value(ref value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns this accessor descriptor's `[[Get]]`, or `None` if this descriptor has no getter.
+    pub fn as_getter(&self) -> Option<&Function<'ducc>> {
+        match self.source {
+            PropertySource::GetSet(ref get, _) | PropertySource::Get(ref get) => Some(get),
+            _ => None,
+        }
+    }
+
+    /// Returns this accessor descriptor's `[[Set]]`, or `None` if this descriptor has no setter.
+    pub fn as_setter(&self) -> Option<&Function<'ducc>> {
+        match self.source {
+            PropertySource::GetSet(_, ref set) | PropertySource::Set(ref set) => Some(set),
+            _ => None,
+        }
+    }
 }
 
 pub struct Properties<'ducc, K, V> {
@@ -0,0 +1,866 @@
+// Optional serde bridge: a `serde::Serializer` that emits `Value`s and a `serde::Deserializer`
+// that walks a `Value`, so arbitrary `Serialize`/`Deserialize` types can cross the JS boundary
+// without the caller building `Object`s field-by-field. Only compiled when the `serde` feature is
+// enabled; see `Ducc::to_value_serde`/`Ducc::from_value_serde`.
+
+use array::{Array, Elements};
+use conversion;
+use ducc::Ducc;
+use error::{Error, ResultExt, Result};
+use object::{Object, Properties};
+use serde;
+use serde::de::IntoDeserializer;
+use std::fmt;
+use string::String as DuccString;
+use value::Value;
+
+impl serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::to_js_conversion("value", "JavaScript value").js_err_context(msg)
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::from_js_conversion("JavaScript value", "value").js_err_context(msg)
+    }
+}
+
+fn serialize_value<'ducc, T>(ducc: &'ducc Ducc, value: &T) -> Result<Value<'ducc>>
+where
+    T: ?Sized + serde::Serialize,
+{
+    value.serialize(Serializer { ducc })
+}
+
+pub(crate) struct Serializer<'ducc> {
+    pub(crate) ducc: &'ducc Ducc,
+}
+
+impl<'ducc> serde::Serializer for Serializer<'ducc> {
+    type Ok = Value<'ducc>;
+    type Error = Error;
+
+    type SerializeSeq = SerializeVec<'ducc>;
+    type SerializeTuple = SerializeVec<'ducc>;
+    type SerializeTupleStruct = SerializeVec<'ducc>;
+    type SerializeTupleVariant = SerializeTupleVariant<'ducc>;
+    type SerializeMap = SerializeMap<'ducc>;
+    type SerializeStruct = SerializeMap<'ducc>;
+    type SerializeStructVariant = SerializeStructVariant<'ducc>;
+
+    #[inline]
+    fn serialize_bool(self, value: bool) -> Result<Value<'ducc>> {
+        Ok(Value::Boolean(value))
+    }
+
+    #[inline]
+    fn serialize_i8(self, value: i8) -> Result<Value<'ducc>> {
+        self.serialize_f64(value as f64)
+    }
+
+    #[inline]
+    fn serialize_i16(self, value: i16) -> Result<Value<'ducc>> {
+        self.serialize_f64(value as f64)
+    }
+
+    #[inline]
+    fn serialize_i32(self, value: i32) -> Result<Value<'ducc>> {
+        self.serialize_f64(value as f64)
+    }
+
+    // Out-of-safe-range values would lose precision round-tripping through `serialize_f64`, so
+    // they're boxed the same lossless way the direct `ToValue for i64` impl boxes them (see
+    // `conversion::to_value_lossless`), rather than silently truncated.
+    #[inline]
+    fn serialize_i64(self, value: i64) -> Result<Value<'ducc>> {
+        if value >= conversion::MIN_SAFE_INTEGER && value <= conversion::MAX_SAFE_INTEGER {
+            self.serialize_f64(value as f64)
+        } else {
+            conversion::to_value_lossless(self.ducc, value.to_string())
+        }
+    }
+
+    #[inline]
+    fn serialize_u8(self, value: u8) -> Result<Value<'ducc>> {
+        self.serialize_f64(value as f64)
+    }
+
+    #[inline]
+    fn serialize_u16(self, value: u16) -> Result<Value<'ducc>> {
+        self.serialize_f64(value as f64)
+    }
+
+    #[inline]
+    fn serialize_u32(self, value: u32) -> Result<Value<'ducc>> {
+        self.serialize_f64(value as f64)
+    }
+
+    // See `serialize_i64` above.
+    #[inline]
+    fn serialize_u64(self, value: u64) -> Result<Value<'ducc>> {
+        if value <= conversion::MAX_SAFE_INTEGER as u64 {
+            self.serialize_f64(value as f64)
+        } else {
+            conversion::to_value_lossless(self.ducc, value.to_string())
+        }
+    }
+
+    #[inline]
+    fn serialize_f32(self, value: f32) -> Result<Value<'ducc>> {
+        self.serialize_f64(value as f64)
+    }
+
+    #[inline]
+    fn serialize_f64(self, value: f64) -> Result<Value<'ducc>> {
+        Ok(Value::Number(value))
+    }
+
+    #[inline]
+    fn serialize_char(self, value: char) -> Result<Value<'ducc>> {
+        let mut string = String::new();
+        string.push(value);
+        self.serialize_str(&string)
+    }
+
+    #[inline]
+    fn serialize_str(self, value: &str) -> Result<Value<'ducc>> {
+        Ok(Value::String(self.ducc.create_string(value)?))
+    }
+
+    #[inline]
+    fn serialize_bytes(self, value: &[u8]) -> Result<Value<'ducc>> {
+        Ok(Value::Bytes(self.ducc.create_bytes(value)?))
+    }
+
+    #[inline]
+    fn serialize_none(self) -> Result<Value<'ducc>> {
+        Ok(Value::Null)
+    }
+
+    #[inline]
+    fn serialize_some<T>(self, value: &T) -> Result<Value<'ducc>>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        value.serialize(self)
+    }
+
+    #[inline]
+    fn serialize_unit(self) -> Result<Value<'ducc>> {
+        Ok(Value::Undefined)
+    }
+
+    #[inline]
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value<'ducc>> {
+        self.serialize_unit()
+    }
+
+    #[inline]
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value<'ducc>> {
+        self.serialize_str(variant)
+    }
+
+    #[inline]
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<Value<'ducc>>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value<'ducc>>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        let object = self.ducc.create_object();
+        let variant = self.ducc.create_string(variant)?;
+        object.set(variant, serialize_value(self.ducc, value)?)?;
+        Ok(Value::Object(object))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Ok(SerializeVec { ducc: self.ducc, array: self.ducc.create_array() })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Ok(SerializeTupleVariant {
+            ducc: self.ducc,
+            name: self.ducc.create_string(variant)?,
+            array: self.ducc.create_array(),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Ok(SerializeMap {
+            ducc: self.ducc,
+            object: self.ducc.create_object(),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Ok(SerializeMap {
+            ducc: self.ducc,
+            object: self.ducc.create_object(),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Ok(SerializeStructVariant {
+            ducc: self.ducc,
+            name: self.ducc.create_string(variant)?,
+            object: self.ducc.create_object(),
+        })
+    }
+}
+
+pub(crate) struct SerializeVec<'ducc> {
+    ducc: &'ducc Ducc,
+    array: Array<'ducc>,
+}
+
+impl<'ducc> serde::ser::SerializeSeq for SerializeVec<'ducc> {
+    type Ok = Value<'ducc>;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        self.array.push(serialize_value(self.ducc, value)?)?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value<'ducc>> {
+        Ok(Value::Array(self.array))
+    }
+}
+
+impl<'ducc> serde::ser::SerializeTuple for SerializeVec<'ducc> {
+    type Ok = Value<'ducc>;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        serde::ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value<'ducc>> {
+        serde::ser::SerializeSeq::end(self)
+    }
+}
+
+impl<'ducc> serde::ser::SerializeTupleStruct for SerializeVec<'ducc> {
+    type Ok = Value<'ducc>;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        serde::ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value<'ducc>> {
+        serde::ser::SerializeSeq::end(self)
+    }
+}
+
+pub(crate) struct SerializeTupleVariant<'ducc> {
+    ducc: &'ducc Ducc,
+    name: DuccString<'ducc>,
+    array: Array<'ducc>,
+}
+
+impl<'ducc> serde::ser::SerializeTupleVariant for SerializeTupleVariant<'ducc> {
+    type Ok = Value<'ducc>;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        self.array.push(serialize_value(self.ducc, value)?)?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value<'ducc>> {
+        let object = self.ducc.create_object();
+        object.set(self.name, self.array)?;
+        Ok(Value::Object(object))
+    }
+}
+
+pub(crate) struct SerializeMap<'ducc> {
+    ducc: &'ducc Ducc,
+    object: Object<'ducc>,
+    next_key: Option<Value<'ducc>>,
+}
+
+impl<'ducc> serde::ser::SerializeMap for SerializeMap<'ducc> {
+    type Ok = Value<'ducc>;
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        self.next_key = Some(serialize_value(self.ducc, key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        let key = self.next_key.take().expect("serialize_value called before serialize_key");
+        self.object.set(key, serialize_value(self.ducc, value)?)?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value<'ducc>> {
+        Ok(Value::Object(self.object))
+    }
+}
+
+impl<'ducc> serde::ser::SerializeStruct for SerializeMap<'ducc> {
+    type Ok = Value<'ducc>;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        serde::ser::SerializeMap::serialize_key(self, key)?;
+        serde::ser::SerializeMap::serialize_value(self, value)
+    }
+
+    fn end(self) -> Result<Value<'ducc>> {
+        serde::ser::SerializeMap::end(self)
+    }
+}
+
+pub(crate) struct SerializeStructVariant<'ducc> {
+    ducc: &'ducc Ducc,
+    name: DuccString<'ducc>,
+    object: Object<'ducc>,
+}
+
+impl<'ducc> serde::ser::SerializeStructVariant for SerializeStructVariant<'ducc> {
+    type Ok = Value<'ducc>;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+    where
+        T: ?Sized + serde::Serialize,
+    {
+        self.object.set(key, serialize_value(self.ducc, value)?)?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value<'ducc>> {
+        let object = self.ducc.create_object();
+        object.set(self.name, self.object)?;
+        Ok(Value::Object(object))
+    }
+}
+
+// Generates a `deserialize_*` method for an integer target type: reads the `f64` out of
+// `Value::Number`, and if it is finite, has no fractional part, and fits within `$min..$end`
+// (`$end` being one past the type's maximum, so that a bound like `i128::MAX as f64`, which rounds
+// up to 2^127, is never accepted and saturated), hands it to `$visit` (widened to the matching
+// 64- or 128-bit visitor method); otherwise reports the loss of precision rather than silently
+// truncating.
+macro_rules! deserialize_number {
+    ($method:ident, $visit:ident, $cast:ty, $name:expr, $min:expr, $end:expr) => {
+        #[inline]
+        fn $method<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: serde::de::Visitor<'de>,
+        {
+            let n = match &self.value {
+                Value::Number(n) => *n,
+                value => return Err(serde::de::Error::custom(
+                    format!("expected a number, found {}", value.type_name())
+                )),
+            };
+
+            if n.is_finite() && n.fract() == 0.0 && n >= $min && n < $end {
+                visitor.$visit(n as $cast)
+            } else {
+                Err(serde::de::Error::custom(
+                    format!("number {} does not fit in {} without loss of precision", n, $name)
+                ))
+            }
+        }
+    };
+}
+
+/// Per-call knobs for `Ducc::from_value_serde_with`, controlling how the walk over a `Value`
+/// handles values and lengths that don't map cleanly onto the target type. `Default` gives the
+/// behavior of `Ducc::from_value_serde`: every option is `false`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DeserializeOptions {
+    /// If `true`, a value with no natural serde representation (a function, typed array, array
+    /// buffer, user data, or symbol) raises an error instead of silently decoding as `()`.
+    pub error_on_undeserializable: bool,
+    /// If `true`, `Value::Undefined` is treated the same as `Value::Null` (and so deserializes as
+    /// `None` for an `Option<T>` field) rather than as a bare unit value.
+    pub undefined_as_none: bool,
+    /// If `true`, trailing array elements or object properties left over after the visitor is done
+    /// consuming them are discarded instead of raising `invalid_length`.
+    pub allow_trailing_elements: bool,
+}
+
+pub(crate) struct Deserializer<'ducc> {
+    pub(crate) ducc: &'ducc Ducc,
+    pub(crate) value: Value<'ducc>,
+    pub(crate) options: DeserializeOptions,
+}
+
+impl<'ducc, 'de> serde::Deserializer<'de> for Deserializer<'ducc> {
+    type Error = Error;
+
+    #[inline]
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>
+    {
+        let options = self.options;
+        match self.value {
+            Value::Undefined if options.undefined_as_none => visitor.visit_none(),
+            Value::Undefined => visitor.visit_unit(),
+            Value::Null => visitor.visit_none(),
+            Value::Boolean(v) => visitor.visit_bool(v),
+            Value::Number(_) => visitor.visit_f64(self.ducc.coerce_number(self.value.clone())?),
+            Value::String(_) => {
+                visitor.visit_string(self.ducc.coerce_string(self.value.clone())?.to_string()?)
+            },
+            Value::Array(v) => {
+                let len = v.len()?;
+                let mut deserializer = SeqDeserializer(self.ducc, v.elements(), options);
+                let seq = visitor.visit_seq(&mut deserializer)?;
+                let remaining = deserializer.1.count();
+                if remaining == 0 || options.allow_trailing_elements {
+                    Ok(seq)
+                } else {
+                    Err(serde::de::Error::invalid_length(len, &"fewer elements in array"))
+                }
+            },
+            Value::Object(v) => {
+                let len = v.len()?;
+                let mut deserializer = MapDeserializer(self.ducc, v.properties(), None, options);
+                let map = visitor.visit_map(&mut deserializer)?;
+                let remaining = deserializer.1.count();
+                if remaining == 0 || options.allow_trailing_elements {
+                    Ok(map)
+                } else {
+                    Err(serde::de::Error::invalid_length(len, &"fewer elements in object"))
+                }
+            },
+            Value::Bytes(v) => visitor.visit_bytes(&v.to_vec()),
+            // Functions, typed arrays, array buffers, user data, and symbols have no natural serde
+            // representation.
+            ref value if options.error_on_undeserializable => Err(serde::de::Error::custom(
+                format!("cannot deserialize {}", value.type_name())
+            )),
+            _ => visitor.visit_unit(),
+        }
+    }
+
+    deserialize_number! { deserialize_i8, visit_i64, i64, "i8", ::std::i8::MIN as f64, ::std::i8::MAX as f64 + 1.0 }
+    deserialize_number! { deserialize_i16, visit_i64, i64, "i16", ::std::i16::MIN as f64, ::std::i16::MAX as f64 + 1.0 }
+    deserialize_number! { deserialize_i32, visit_i64, i64, "i32", ::std::i32::MIN as f64, ::std::i32::MAX as f64 + 1.0 }
+    deserialize_number! { deserialize_i128, visit_i128, i128, "i128", ::std::i128::MIN as f64, 170_141_183_460_469_231_731_687_303_715_884_105_728.0 }
+    deserialize_number! { deserialize_u8, visit_u64, u64, "u8", 0.0, ::std::u8::MAX as f64 + 1.0 }
+    deserialize_number! { deserialize_u16, visit_u64, u64, "u16", 0.0, ::std::u16::MAX as f64 + 1.0 }
+    deserialize_number! { deserialize_u32, visit_u64, u64, "u32", 0.0, ::std::u32::MAX as f64 + 1.0 }
+    deserialize_number! { deserialize_u128, visit_u128, u128, "u128", 0.0, 340_282_366_920_938_463_463_374_607_431_768_211_456.0 }
+
+    // `i64`/`u64` diverge from the other widths generated by `deserialize_number!` above: a
+    // `ToValue`-produced out-of-safe-range integer arrives boxed as
+    // `{ __ducc_lossless_integer__: "<decimal digits>" }` rather than a `Value::Number` (see
+    // `conversion::to_value_lossless`), so these two check for that shape first and parse its
+    // digits directly, falling back to the usual `Value::Number` handling otherwise.
+    #[inline]
+    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        if let Some(digits) = conversion::from_value_lossless(&self.value)? {
+            let n: i64 = digits.parse().map_err(|_| serde::de::Error::custom(
+                format!("lossless integer object does not contain a valid i64: {:?}", digits)
+            ))?;
+            return visitor.visit_i64(n);
+        }
+
+        let n = match &self.value {
+            Value::Number(n) => *n,
+            value => return Err(serde::de::Error::custom(
+                format!("expected a number, found {}", value.type_name())
+            )),
+        };
+
+        // `i64::MAX as f64` rounds up to exactly 2^63 (one past the true max), so it must be
+        // compared against with a strict `<`, not `<=`, or `2f64.powi(63)` would wrongly pass and
+        // then saturate to `i64::MAX` in the cast below instead of raising this precision error.
+        if n.is_finite() && n.fract() == 0.0 && n >= ::std::i64::MIN as f64 && n < 9_223_372_036_854_775_808.0 {
+            visitor.visit_i64(n as i64)
+        } else {
+            Err(serde::de::Error::custom(
+                format!("number {} does not fit in i64 without loss of precision", n)
+            ))
+        }
+    }
+
+    // See `deserialize_i64` above.
+    #[inline]
+    fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        if let Some(digits) = conversion::from_value_lossless(&self.value)? {
+            let n: u64 = digits.parse().map_err(|_| serde::de::Error::custom(
+                format!("lossless integer object does not contain a valid u64: {:?}", digits)
+            ))?;
+            return visitor.visit_u64(n);
+        }
+
+        let n = match &self.value {
+            Value::Number(n) => *n,
+            value => return Err(serde::de::Error::custom(
+                format!("expected a number, found {}", value.type_name())
+            )),
+        };
+
+        // See the i64 bound comment above; `u64::MAX as f64` rounds up to exactly 2^64.
+        if n.is_finite() && n.fract() == 0.0 && n >= 0.0 && n < 18_446_744_073_709_551_616.0 {
+            visitor.visit_u64(n as u64)
+        } else {
+            Err(serde::de::Error::custom(
+                format!("number {} does not fit in u64 without loss of precision", n)
+            ))
+        }
+    }
+
+    #[inline]
+    fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>
+    {
+        match &self.value {
+            Value::Number(n) => visitor.visit_f32(*n as f32),
+            value => Err(serde::de::Error::custom(
+                format!("expected a number, found {}", value.type_name())
+            )),
+        }
+    }
+
+    #[inline]
+    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>
+    {
+        match &self.value {
+            Value::Number(n) => visitor.visit_f64(*n),
+            value => Err(serde::de::Error::custom(
+                format!("expected a number, found {}", value.type_name())
+            )),
+        }
+    }
+
+    #[inline]
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>
+    {
+        match self.value {
+            Value::Null | Value::Undefined => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    #[inline]
+    fn deserialize_enum<V>(
+        self,
+        _name: &str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>
+    {
+        let ducc = self.ducc;
+        let options = self.options;
+        let (variant, value) = match self.value {
+            Value::Object(value) => {
+                let mut iter = value.properties::<Value, Value>();
+                let (variant, value) = match iter.next() {
+                    Some(v) => v?,
+                    None => return Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Map,
+                        &"map with a single key",
+                    )),
+                };
+
+                if iter.next().is_some() {
+                    return Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Map,
+                        &"map with a single key",
+                    ))
+                }
+
+                let variant = ducc.coerce_string(variant)?.to_string()?;
+                (variant, Some(value))
+            },
+            value @ Value::String(_) => {
+                (ducc.coerce_string(value)?.to_string()?, None)
+            },
+            _ => return Err(serde::de::Error::custom("bad enum value")),
+        };
+
+        visitor.visit_enum(EnumDeserializer { ducc, variant, value, options })
+    }
+
+    // Unlike `deserialize_any`, this never materializes the value it skips: scalars, strings, and
+    // byte buffers are discarded without decoding or copying, and arrays/objects only advance their
+    // underlying `Elements`/`Properties` iterators (recursively skipping each element in turn, since
+    // each one must still be popped off the Duktape stack) rather than collecting into a `Value`.
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>
+    {
+        let options = self.options;
+        match self.value {
+            Value::Array(v) => {
+                for value in v.elements::<Value>() {
+                    serde::Deserializer::deserialize_ignored_any(
+                        Deserializer { ducc: self.ducc, value: value?, options },
+                        serde::de::IgnoredAny,
+                    )?;
+                }
+            },
+            Value::Object(v) => {
+                for item in v.properties::<Value, Value>() {
+                    let (_, value) = item?;
+                    serde::Deserializer::deserialize_ignored_any(
+                        Deserializer { ducc: self.ducc, value, options },
+                        serde::de::IgnoredAny,
+                    )?;
+                }
+            },
+            _ => {},
+        }
+
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool char str string bytes
+        byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier
+    }
+}
+
+struct SeqDeserializer<'ducc>(&'ducc Ducc, Elements<'ducc, Value<'ducc>>, DeserializeOptions);
+
+impl<'ducc, 'de> serde::de::SeqAccess<'de> for SeqDeserializer<'ducc> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: serde::de::DeserializeSeed<'de>
+    {
+        match self.1.next() {
+            Some(value) => {
+                seed.deserialize(
+                    Deserializer { ducc: self.0, value: value?, options: self.2 }
+                ).map(Some)
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        match self.1.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(upper),
+            _ => None,
+        }
+    }
+}
+
+struct MapDeserializer<'ducc>(
+    &'ducc Ducc,
+    Properties<'ducc, Value<'ducc>, Value<'ducc>>,
+    Option<Value<'ducc>>,
+    DeserializeOptions,
+);
+
+impl<'ducc, 'de> serde::de::MapAccess<'de> for MapDeserializer<'ducc> {
+    type Error = Error;
+
+    fn next_key_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: serde::de::DeserializeSeed<'de>
+    {
+        match self.1.next() {
+            Some(item) => {
+                let (key, value) = item?;
+                self.2 = Some(value);
+                seed.deserialize(
+                    Deserializer { ducc: self.0, value: key, options: self.3 }
+                ).map(Some)
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<T>(&mut self, seed: T) -> Result<T::Value>
+    where
+        T: serde::de::DeserializeSeed<'de>
+    {
+        match self.2.take() {
+            Some(value) => seed.deserialize(Deserializer { ducc: self.0, value, options: self.3 }),
+            None => Err(serde::de::Error::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        match self.1.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(upper),
+            _ => None,
+        }
+    }
+}
+
+struct EnumDeserializer<'ducc> {
+    ducc: &'ducc Ducc,
+    variant: String,
+    value: Option<Value<'ducc>>,
+    options: DeserializeOptions,
+}
+
+impl<'ducc, 'de> serde::de::EnumAccess<'de> for EnumDeserializer<'ducc> {
+    type Error = Error;
+    type Variant = VariantDeserializer<'ducc>;
+
+    fn variant_seed<T>(self, seed: T) -> Result<(T::Value, Self::Variant)>
+    where
+        T: serde::de::DeserializeSeed<'de>
+    {
+        let variant = self.variant.into_deserializer();
+        let variant_access = VariantDeserializer {
+            ducc: self.ducc,
+            value: self.value,
+            options: self.options,
+        };
+        seed.deserialize(variant).map(|v| (v, variant_access))
+    }
+}
+
+struct VariantDeserializer<'ducc> {
+    ducc: &'ducc Ducc,
+    value: Option<Value<'ducc>>,
+    options: DeserializeOptions,
+}
+
+impl<'ducc, 'de> serde::de::VariantAccess<'de> for VariantDeserializer<'ducc> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        match self.value {
+            Some(_) => Err(serde::de::Error::invalid_type(
+                serde::de::Unexpected::NewtypeVariant,
+                &"unit variant",
+            )),
+            None => Ok(())
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value>
+    where
+        T: serde::de::DeserializeSeed<'de>
+    {
+        match self.value {
+            Some(value) => {
+                seed.deserialize(Deserializer { ducc: self.ducc, value, options: self.options })
+            },
+            None => Err(serde::de::Error::invalid_type(
+                serde::de::Unexpected::UnitVariant,
+                &"newtype variant",
+            ))
+        }
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>
+    {
+        match self.value {
+            Some(value) => serde::Deserializer::deserialize_seq(
+                Deserializer { ducc: self.ducc, value, options: self.options },
+                visitor,
+            ),
+            None => Err(serde::de::Error::invalid_type(
+                serde::de::Unexpected::UnitVariant,
+                &"tuple variant",
+            ))
+        }
+    }
+
+    fn struct_variant<V>(
+        self, _fields: &'static [&'static str], visitor: V
+    ) -> Result<V::Value>
+        where V: serde::de::Visitor<'de>
+    {
+        match self.value {
+            Some(value) => serde::Deserializer::deserialize_map(
+                Deserializer { ducc: self.ducc, value, options: self.options },
+                visitor,
+            ),
+            None => Err(serde::de::Error::invalid_type(
+                serde::de::Unexpected::UnitVariant,
+                &"struct variant",
+            ))
+        }
+    }
+}
@@ -0,0 +1,196 @@
+// Optional standard-library shims: a curated set of globals missing from bare Duktape. Nothing
+// here is installed by default; `Ducc::load_stdlib` installs exactly the pieces a `StdlibConfig`
+// opts into, so a heap that never calls it pays nothing for them.
+
+use ducc::Ducc;
+use error::{Error, Result, RuntimeError, RuntimeErrorCode};
+use std::fmt;
+use std::string::String as StdString;
+use std::sync::Arc;
+
+/// The severity a `console` method was invoked with, passed through to a `StdlibConfig`'s console
+/// sink alongside the formatted message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConsoleLevel {
+    Log,
+    Warn,
+    Error,
+}
+
+/// Configures which pieces of `Ducc::load_stdlib`'s standard-library shims are installed.
+///
+/// `StdlibConfig::new()` installs nothing; opt into each piece with its builder method.
+pub struct StdlibConfig {
+    console: Option<Arc<dyn Fn(ConsoleLevel, &str) + Send + Sync>>,
+    base64: bool,
+}
+
+impl StdlibConfig {
+    pub fn new() -> StdlibConfig {
+        StdlibConfig { console: None, base64: false }
+    }
+
+    /// Installs a `console` global whose `log`/`warn`/`error` methods coerce their arguments to
+    /// strings (the same coercion `Ducc::coerce_string` performs), join them with a single space,
+    /// and forward the result to `sink` along with the level it was called at.
+    ///
+    /// Defaults to not installing `console` at all.
+    pub fn console<F>(mut self, sink: F) -> StdlibConfig
+    where
+        F: 'static + Send + Sync + Fn(ConsoleLevel, &str),
+    {
+        self.console = Some(Arc::new(sink));
+        self
+    }
+
+    /// Installs the `btoa`/`atob` globals, converting between a "binary string" (one character per
+    /// byte) and its base64 encoding, matching the browser functions of the same name.
+    ///
+    /// Defaults to `false`.
+    pub fn base64(mut self, enabled: bool) -> StdlibConfig {
+        self.base64 = enabled;
+        self
+    }
+}
+
+#[derive(Debug)]
+struct InvalidCharacterError(&'static str);
+
+impl fmt::Display for InvalidCharacterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl RuntimeError for InvalidCharacterError {
+    fn code(&self) -> RuntimeErrorCode {
+        RuntimeErrorCode::Custom("InvalidCharacterError".to_string())
+    }
+
+    fn message(&self) -> Option<StdString> {
+        Some(self.to_string())
+    }
+}
+
+pub(crate) fn load(ducc: &Ducc, config: StdlibConfig) -> Result<()> {
+    let globals = ducc.globals();
+
+    if let Some(sink) = config.console {
+        let console = ducc.create_object();
+        for &(name, level) in &[
+            ("log", ConsoleLevel::Log),
+            ("warn", ConsoleLevel::Warn),
+            ("error", ConsoleLevel::Error),
+        ] {
+            let sink = sink.clone();
+            console.set(name, ducc.create_function(move |inv| -> Result<()> {
+                let mut message = StdString::new();
+                for (i, arg) in inv.args.iter().enumerate() {
+                    if i > 0 {
+                        message.push(' ');
+                    }
+                    message.push_str(&inv.ducc.coerce_string(arg.clone())?.to_string()?);
+                }
+                sink(level, &message);
+                Ok(())
+            }))?;
+        }
+        globals.set("console", console)?;
+    }
+
+    if config.base64 {
+        globals.set("btoa", ducc.create_function(|inv| -> Result<StdString> {
+            let input: StdString = inv.args.from(inv.ducc, 0)?;
+            let mut bytes = Vec::with_capacity(input.len());
+            for c in input.chars() {
+                if c as u32 > 0xff {
+                    return Err(Error::external(InvalidCharacterError(
+                        "string contains characters outside of the Latin1 range"
+                    )));
+                }
+                bytes.push(c as u8);
+            }
+            Ok(base64_encode(&bytes))
+        }))?;
+
+        globals.set("atob", ducc.create_function(|inv| -> Result<StdString> {
+            let input: StdString = inv.args.from(inv.ducc, 0)?;
+            let bytes = base64_decode(&input).ok_or_else(|| {
+                Error::external(InvalidCharacterError("string is not correctly base64-encoded"))
+            })?;
+            Ok(bytes.into_iter().map(|b| b as char).collect())
+        }))?;
+    }
+
+    Ok(())
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_encode(bytes: &[u8]) -> StdString {
+    let mut out = StdString::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = *chunk.get(1).unwrap_or(&0);
+        let b2 = *chunk.get(2).unwrap_or(&0);
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[(b2 & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
+
+fn base64_decode(input: &str) -> Option<Vec<u8>> {
+    fn digit_value(c: u8) -> Option<u8> {
+        if c >= b'A' && c <= b'Z' {
+            Some(c - b'A')
+        } else if c >= b'a' && c <= b'z' {
+            Some(c - b'a' + 26)
+        } else if c >= b'0' && c <= b'9' {
+            Some(c - b'0' + 52)
+        } else if c == b'+' {
+            Some(62)
+        } else if c == b'/' {
+            Some(63)
+        } else {
+            None
+        }
+    }
+
+    let trimmed = input.trim_end_matches('=');
+    if !trimmed.is_ascii() {
+        return None;
+    }
+
+    if trimmed.len() % 4 == 1 {
+        return None;
+    }
+
+    let digits = trimmed.as_bytes().iter()
+        .map(|&c| digit_value(c))
+        .collect::<Option<Vec<u8>>>()?;
+
+    let mut out = Vec::with_capacity(digits.len() * 3 / 4);
+    for group in digits.chunks(4) {
+        let g1 = *group.get(1).unwrap_or(&0);
+        out.push((group[0] << 2) | (g1 >> 4));
+        if group.len() > 2 {
+            out.push((group[1] << 4) | (group[2] >> 2));
+        }
+        if group.len() > 3 {
+            out.push((group[2] << 6) | group[3]);
+        }
+    }
+    Some(out)
+}
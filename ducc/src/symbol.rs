@@ -0,0 +1,6 @@
+use types::Ref;
+
+/// Reference to a JavaScript `Symbol`, usable as a property key alongside strings wherever an
+/// `Object` method accepts a `ToValue` key.
+#[derive(Clone, Debug)]
+pub struct Symbol<'ducc>(pub(crate) Ref<'ducc>);
@@ -43,6 +43,137 @@ fn push() {
     assert_eq!(array.len().unwrap(), 5);
 }
 
+#[test]
+fn create_array_from() {
+    let ducc = Ducc::new();
+
+    let array = ducc.create_array_from(vec![1, 2, 3]).unwrap();
+    let list: Result<Vec<usize>, _> = array.elements().collect();
+    assert_eq!(list.unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn create_array_from_fn() {
+    let ducc = Ducc::new();
+
+    let array = ducc.create_array_from_fn(5, |i| i * 2).unwrap();
+    let list: Result<Vec<u32>, _> = array.elements().collect();
+    assert_eq!(list.unwrap(), vec![0, 2, 4, 6, 8]);
+}
+
+#[test]
+fn pop() {
+    let ducc = Ducc::new();
+
+    let array = ducc.create_array();
+    array.push(1).unwrap();
+    array.push(2).unwrap();
+    assert_eq!(array.pop::<usize>().unwrap(), 2);
+    assert_eq!(array.len().unwrap(), 1);
+    assert_eq!(array.pop::<usize>().unwrap(), 1);
+    assert_eq!(array.len().unwrap(), 0);
+}
+
+#[test]
+fn shift_and_unshift() {
+    let ducc = Ducc::new();
+
+    let array = ducc.create_array();
+    array.push(1).unwrap();
+    array.push(2).unwrap();
+
+    array.unshift(0).unwrap();
+    assert_eq!(array.len().unwrap(), 3);
+    assert_eq!(array.get::<usize>(0).unwrap(), 0);
+
+    assert_eq!(array.shift::<usize>().unwrap(), 0);
+    assert_eq!(array.len().unwrap(), 2);
+    assert_eq!(array.get::<usize>(0).unwrap(), 1);
+    assert_eq!(array.get::<usize>(1).unwrap(), 2);
+}
+
+#[test]
+fn splice() {
+    let ducc = Ducc::new();
+
+    let array = ducc.create_array();
+    array.extend(vec![1, 2, 3, 4, 5]).unwrap();
+
+    let removed = array.splice(1, 2, vec![20, 30, 40]).unwrap();
+    let removed: Result<Vec<usize>, _> = removed.elements().collect();
+    assert_eq!(removed.unwrap(), vec![2, 3]);
+
+    let remaining: Result<Vec<usize>, _> = array.elements().collect();
+    assert_eq!(remaining.unwrap(), vec![1, 20, 30, 40, 4, 5]);
+}
+
+#[test]
+fn insert_and_remove() {
+    let ducc = Ducc::new();
+
+    let array = ducc.create_array();
+    array.extend(vec![1, 2, 4]).unwrap();
+
+    array.insert(2, 3).unwrap();
+    let list: Result<Vec<usize>, _> = array.elements().collect();
+    assert_eq!(list.unwrap(), vec![1, 2, 3, 4]);
+
+    assert_eq!(array.remove::<usize>(0).unwrap(), 1);
+    let list: Result<Vec<usize>, _> = array.elements().collect();
+    assert_eq!(list.unwrap(), vec![2, 3, 4]);
+}
+
+#[test]
+fn extend() {
+    let ducc = Ducc::new();
+
+    let array = ducc.create_array();
+    array.push(0).unwrap();
+    array.extend(vec![1, 2, 3]).unwrap();
+    assert_eq!(array.len().unwrap(), 4);
+    assert_eq!(array.get::<usize>(0).unwrap(), 0);
+    assert_eq!(array.get::<usize>(1).unwrap(), 1);
+    assert_eq!(array.get::<usize>(2).unwrap(), 2);
+    assert_eq!(array.get::<usize>(3).unwrap(), 3);
+}
+
+#[test]
+fn append() {
+    let ducc = Ducc::new();
+
+    let a = ducc.create_array();
+    a.push(1).unwrap();
+    a.push(2).unwrap();
+
+    let b = ducc.create_array();
+    b.push(3).unwrap();
+    b.push(4).unwrap();
+
+    a.append(&b).unwrap();
+    let list: Result<Vec<usize>, _> = a.elements().collect();
+    assert_eq!(list.unwrap(), vec![1, 2, 3, 4]);
+    assert_eq!(b.len().unwrap(), 2);
+}
+
+#[test]
+fn to_fixed() {
+    let ducc = Ducc::new();
+
+    let array = ducc.create_array();
+    array.extend(vec![1, 2, 3]).unwrap();
+    let fixed: [usize; 3] = array.to_fixed().unwrap();
+    assert_eq!(fixed, [1, 2, 3]);
+}
+
+#[test]
+fn to_fixed_length_mismatch() {
+    let ducc = Ducc::new();
+
+    let array = ducc.create_array();
+    array.extend(vec![1, 2]).unwrap();
+    assert!(array.to_fixed::<usize, 3>().is_err());
+}
+
 #[test]
 fn elements() {
     let ducc = Ducc::new();
@@ -56,3 +187,52 @@ fn elements() {
     let list: Result<Vec<usize>, _> = array.elements().collect();
     assert_eq!(list.unwrap(), vec![0, 1, 0, 3, 4]);
 }
+
+#[test]
+fn elements_reversed() {
+    let ducc = Ducc::new();
+
+    let array = ducc.create_array();
+    array.push(0).unwrap();
+    array.push(1).unwrap();
+    array.push(2).unwrap();
+
+    let list: Result<Vec<usize>, _> = array.elements().rev().collect();
+    assert_eq!(list.unwrap(), vec![2, 1, 0]);
+}
+
+#[test]
+fn elements_from_both_ends() {
+    let ducc = Ducc::new();
+
+    let array = ducc.create_array();
+    array.push(0).unwrap();
+    array.push(1).unwrap();
+    array.push(2).unwrap();
+    array.push(3).unwrap();
+
+    let mut elements = array.elements::<usize>();
+    assert_eq!(elements.next().unwrap().unwrap(), 0);
+    assert_eq!(elements.next_back().unwrap().unwrap(), 3);
+    assert_eq!(elements.next_back().unwrap().unwrap(), 2);
+    assert_eq!(elements.next().unwrap().unwrap(), 1);
+    assert!(elements.next().is_none());
+    assert!(elements.next_back().is_none());
+}
+
+#[test]
+fn elements_exact_size() {
+    let ducc = Ducc::new();
+
+    let array = ducc.create_array();
+    array.push(0).unwrap();
+    array.push(1).unwrap();
+    array.push(2).unwrap();
+
+    let mut elements = array.elements::<usize>();
+    assert_eq!(elements.len(), 3);
+    elements.next();
+    assert_eq!(elements.len(), 2);
+    elements.next_back();
+    assert_eq!(elements.len(), 1);
+}
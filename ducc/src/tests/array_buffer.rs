@@ -0,0 +1,49 @@
+use ducc::Ducc;
+use typed_array::TypedSlice;
+use value::{ToValue, Value};
+
+#[test]
+fn create_array_buffer_has_requested_length() {
+    let ducc = Ducc::new();
+    let buffer = ducc.create_array_buffer(16).unwrap();
+    assert_eq!(buffer.len(), 16);
+}
+
+#[test]
+fn create_array_buffer_is_zeroed() {
+    let ducc = Ducc::new();
+    let buffer = ducc.create_array_buffer(4).unwrap();
+    ducc.globals().set("buf", buffer).unwrap();
+    let sum: f64 = ducc.exec(
+        "var view = new Uint8Array(buf), sum = 0; \
+         for (var i = 0; i < view.length; i++) { sum += view[i]; } sum",
+        None,
+        Default::default(),
+    ).unwrap();
+    assert_eq!(sum, 0.0);
+}
+
+#[test]
+fn script_created_array_buffer_round_trips_as_a_distinct_type() {
+    let ducc = Ducc::new();
+    let value = ducc.exec::<Value>("new ArrayBuffer(8)", None, Default::default()).unwrap();
+    assert_eq!(value.type_name(), "array buffer");
+    assert!(value.as_array_buffer().is_some());
+}
+
+#[test]
+fn array_buffer_backs_a_typed_array_view() {
+    let ducc = Ducc::new();
+    let buffer = ducc.create_array_buffer(8).unwrap();
+    ducc.globals().set("buf", buffer).unwrap();
+    ducc.exec::<()>("new Uint8Array(buf)[0] = 42;", None, Default::default()).unwrap();
+    let result: f64 = ducc.exec("new Uint8Array(buf)[0]", None, Default::default()).unwrap();
+    assert_eq!(result, 42.0);
+}
+
+#[test]
+fn typed_slice_stays_distinct_from_array_buffer() {
+    let ducc = Ducc::new();
+    let value = TypedSlice(vec![1u8, 2, 3]).to_value(&ducc).unwrap();
+    assert_ne!(value.type_name(), "array buffer");
+}
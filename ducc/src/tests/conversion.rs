@@ -122,3 +122,38 @@ fn hash_set() {
         .unwrap().elements().collect();
     assert_eq!(list.unwrap(), vec![1, 2, 3].into_iter().collect());
 }
+
+#[test]
+fn i64_round_trips_within_safe_range() {
+    let ducc = Ducc::new();
+    ducc.globals().set("n", 42i64).unwrap();
+    let result: i64 = ducc.exec("n + 1", None, Default::default()).unwrap();
+    assert_eq!(result, 43);
+}
+
+#[test]
+fn i64_round_trips_losslessly_beyond_safe_range() {
+    let ducc = Ducc::new();
+    let value = i64::max_value();
+    ducc.globals().set("n", value).unwrap();
+    let result: i64 = ducc.globals().get("n").unwrap();
+    assert_eq!(result, value);
+}
+
+#[test]
+fn i64_negative_round_trips_losslessly_beyond_safe_range() {
+    let ducc = Ducc::new();
+    let value = i64::min_value();
+    ducc.globals().set("n", value).unwrap();
+    let result: i64 = ducc.globals().get("n").unwrap();
+    assert_eq!(result, value);
+}
+
+#[test]
+fn u64_round_trips_losslessly_beyond_safe_range() {
+    let ducc = Ducc::new();
+    let value = u64::max_value();
+    ducc.globals().set("n", value).unwrap();
+    let result: u64 = ducc.globals().get("n").unwrap();
+    assert_eq!(result, value);
+}
@@ -1,7 +1,10 @@
-use ducc::{Ducc, ExecSettings};
+use ducc::{BinaryOp, Ducc, ExecSettings};
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::thread;
 use std::time::{Duration, Instant};
+use stdlib::{ConsoleLevel, StdlibConfig};
 use value::Value;
 
 #[test]
@@ -19,11 +22,234 @@ fn timeout() {
     let ducc = Ducc::new();
     let start = Instant::now();
     let cancel_fn = move || Instant::now().duration_since(start) > Duration::from_millis(500);
-    let settings = ExecSettings { cancel_fn: Some(Box::new(cancel_fn)) };
+    let settings = ExecSettings { cancel_fn: Some(Box::new(cancel_fn)), ..ExecSettings::default() };
     let result: Result<(), _> = ducc.exec("for (;;) {}", None, settings);
     assert!(result.is_err());
 }
 
+#[test]
+fn deadline() {
+    let ducc = Ducc::new();
+    let settings = ExecSettings::with_deadline(Duration::from_millis(500));
+    let result: Result<(), _> = ducc.exec("for (;;) {}", None, settings);
+    assert!(result.is_err());
+}
+
+#[test]
+fn deadline_does_not_linger_past_its_exec() {
+    let ducc = Ducc::new();
+    let settings = ExecSettings::with_deadline(Duration::from_millis(1));
+    let _ = ducc.exec::<()>("1 + 1", None, settings);
+    thread::sleep(Duration::from_millis(50));
+    let result: Result<i32, _> = ducc.exec("1 + 1", None, ExecSettings::default());
+    assert_eq!(result.unwrap(), 2);
+}
+
+#[test]
+#[cfg(feature = "memory_limit")]
+fn memory_limit() {
+    let ducc = Ducc::new();
+    let settings = ExecSettings::with_memory_limit(1024);
+    let result: Result<(), _> = ducc.exec("var a = []; for (;;) { a.push(1); }", None, settings);
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "memory_limit")]
+fn memory_limit_does_not_linger_past_its_exec() {
+    let ducc = Ducc::new();
+    let settings = ExecSettings::with_memory_limit(1);
+    let _ = ducc.exec::<()>("1 + 1", None, settings);
+    let result: Result<i32, _> = ducc.exec("1 + 1", None, ExecSettings::default());
+    assert_eq!(result.unwrap(), 2);
+}
+
+#[test]
+#[cfg(feature = "memory_limit")]
+fn set_memory_limit() {
+    let ducc = Ducc::new();
+    ducc.set_memory_limit(Some(1024));
+    let result: Result<(), _> = ducc.exec("var a = []; for (;;) { a.push(1); }", None, ExecSettings::default());
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "memory_limit")]
+fn set_memory_limit_lingers_across_execs_unlike_exec_settings() {
+    let ducc = Ducc::new();
+    ducc.set_memory_limit(Some(1));
+    let result: Result<i32, _> = ducc.exec("1 + 1", None, ExecSettings::default());
+    assert!(result.is_err());
+    ducc.set_memory_limit(None);
+    let result: Result<i32, _> = ducc.exec("1 + 1", None, ExecSettings::default());
+    assert_eq!(result.unwrap(), 2);
+}
+
+#[test]
+#[cfg(feature = "memory_limit")]
+fn memory_usage_and_peak_memory_usage() {
+    let ducc = Ducc::new();
+    let baseline = ducc.memory_usage();
+    assert_eq!(ducc.peak_memory_usage(), baseline);
+
+    let _: () = ducc.exec("var a = new Array(1000).fill(0);", None, ExecSettings::default()).unwrap();
+    assert!(ducc.peak_memory_usage() > baseline);
+    assert!(ducc.peak_memory_usage() >= ducc.memory_usage());
+}
+
+#[test]
+fn set_interrupt_step_budget() {
+    let ducc = Ducc::new();
+    let steps = Rc::new(RefCell::new(0));
+    let counted_steps = steps.clone();
+    ducc.set_interrupt(move || {
+        *counted_steps.borrow_mut() += 1;
+        *counted_steps.borrow() > 3
+    });
+    let result: Result<(), _> = ducc.exec("for (;;) {}", None, ExecSettings::default());
+    assert!(result.is_err());
+    assert!(*steps.borrow() > 3);
+}
+
+#[test]
+fn set_interrupt_lingers_across_execs_unlike_cancel_fn() {
+    let ducc = Ducc::new();
+    ducc.set_interrupt(|| true);
+    let result: Result<i32, _> = ducc.exec("1 + 1", None, ExecSettings::default());
+    assert!(result.is_err());
+    let result: Result<i32, _> = ducc.exec("1 + 1", None, ExecSettings::default());
+    assert!(result.is_err());
+}
+
+#[test]
+fn clear_interrupt() {
+    let ducc = Ducc::new();
+    ducc.set_interrupt(|| true);
+    ducc.clear_interrupt();
+    let result: Result<i32, _> = ducc.exec("1 + 1", None, ExecSettings::default());
+    assert_eq!(result.unwrap(), 2);
+}
+
+#[test]
+fn interrupt_handle() {
+    let ducc = Ducc::new();
+    let handle = ducc.interrupt_handle();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        handle.cancel();
+    });
+    let result: Result<(), _> = ducc.exec("for (;;) {}", None, ExecSettings::default());
+    assert!(result.is_err());
+}
+
+#[test]
+fn interrupt_handle_reset() {
+    let ducc = Ducc::new();
+    let handle = ducc.interrupt_handle();
+    handle.cancel();
+    handle.reset();
+    let result: Result<i32, _> = ducc.exec("1 + 1", None, ExecSettings::default());
+    assert_eq!(result.unwrap(), 2);
+}
+
+#[test]
+fn binary_op() {
+    let ducc = Ducc::new();
+    let a = Value::String(ducc.create_string("foo").unwrap());
+    let b = Value::String(ducc.create_string("bar").unwrap());
+    let result = ducc.binary_op(BinaryOp::Add, a, b).unwrap();
+    assert_eq!(result.as_string().unwrap().to_string(), "foobar");
+
+    let result = ducc.binary_op(BinaryOp::Mul, Value::Number(6.0), Value::Number(7.0)).unwrap();
+    assert_eq!(result.as_number(), Some(42.0));
+
+    let result = ducc.binary_op(BinaryOp::Lt, Value::Number(1.0), Value::Number(2.0)).unwrap();
+    assert_eq!(result.as_boolean(), Some(true));
+}
+
+#[test]
+fn strict_equals() {
+    let ducc = Ducc::new();
+    assert!(ducc.strict_equals(&Value::Number(0.0), &Value::Number(-0.0)));
+    let nan = Value::Number(::std::f64::NAN);
+    assert!(!ducc.strict_equals(&nan, &nan.clone()));
+    assert!(!ducc.strict_equals(&Value::Number(0.0), &Value::Boolean(false)));
+}
+
+#[test]
+fn abstract_equals() {
+    let ducc = Ducc::new();
+    assert!(ducc.abstract_equals(&Value::Null, &Value::Undefined).unwrap());
+    assert!(ducc.abstract_equals(&Value::Number(0.0), &Value::Boolean(false)).unwrap());
+    assert!(!ducc.abstract_equals(&Value::Null, &Value::Number(0.0)).unwrap());
+}
+
+#[test]
+fn decode_json() {
+    let ducc = Ducc::new();
+    let value = ducc.decode_json(r#"{"a": 1, "b": [2, 3]}"#).unwrap();
+    let object = value.as_object().unwrap();
+    assert_eq!(object.get::<_, i8>("a").unwrap(), 1);
+    assert_eq!(object.get::<_, Vec<i8>>("b").unwrap(), vec![2, 3]);
+}
+
+#[test]
+fn decode_json_malformed_is_error() {
+    let ducc = Ducc::new();
+    assert!(ducc.decode_json("not json").is_err());
+}
+
+#[test]
+fn encode_json() {
+    let ducc = Ducc::new();
+    let object = ducc.create_object();
+    object.set("a", 1).unwrap();
+    let json = ducc.encode_json(Value::Object(object)).unwrap();
+    assert_eq!(json.to_string().unwrap(), r#"{"a":1}"#);
+}
+
+#[test]
+fn json_round_trip() {
+    let ducc = Ducc::new();
+    let value = ducc.decode_json("[1, 2, 3]").unwrap();
+    let json = ducc.encode_json(value).unwrap();
+    assert_eq!(json.to_string().unwrap(), "[1,2,3]");
+}
+
+#[test]
+fn bytecode_round_trip() {
+    let bytecode = {
+        let ducc = Ducc::new();
+        let func = ducc.compile("21 + 21", None).unwrap();
+        ducc.dump_bytecode(&func).unwrap()
+    };
+
+    let ducc = Ducc::new();
+    let func = ducc.load_bytecode(&bytecode).unwrap();
+    let result: f64 = func.call(()).unwrap();
+    assert_eq!(result, 42.0);
+}
+
+#[test]
+fn function_dump_bytecode_round_trip() {
+    let bytecode = {
+        let ducc = Ducc::new();
+        let func = ducc.compile("21 + 21", None).unwrap();
+        func.dump_bytecode().unwrap().to_vec()
+    };
+
+    let ducc = Ducc::new();
+    let func = ducc.load_bytecode(&bytecode).unwrap();
+    let result: f64 = func.call(()).unwrap();
+    assert_eq!(result, 42.0);
+}
+
+#[test]
+fn load_bytecode_invalid() {
+    let ducc = Ducc::new();
+    assert!(ducc.load_bytecode(&[1, 2, 3, 4]).is_err());
+}
+
 #[test]
 fn no_duktape_global() {
     let ducc = Ducc::new();
@@ -41,6 +267,53 @@ fn inspect_callstack() {
     ducc.globals().get::<_, Function>("fun").unwrap().call::<(), ()>(()).unwrap();
 }
 
+#[test]
+fn load_stdlib_noop_by_default() {
+    let ducc = Ducc::new();
+    ducc.load_stdlib(StdlibConfig::new()).unwrap();
+    assert!(!ducc.globals().contains_key("console").unwrap());
+    assert!(!ducc.globals().contains_key("btoa").unwrap());
+}
+
+#[test]
+fn load_stdlib_console() {
+    let ducc = Ducc::new();
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    let sink = messages.clone();
+    let config = StdlibConfig::new().console(move |level, message| {
+        sink.lock().unwrap().push((level, message.to_string()));
+    });
+    ducc.load_stdlib(config).unwrap();
+
+    ducc.exec::<()>(r#"console.log("a", 1, true); console.warn("b");"#, None, ExecSettings::default()).unwrap();
+
+    let messages = messages.lock().unwrap();
+    assert_eq!(*messages, vec![
+        (ConsoleLevel::Log, "a 1 true".to_string()),
+        (ConsoleLevel::Warn, "b".to_string()),
+    ]);
+}
+
+#[test]
+fn load_stdlib_base64_round_trip() {
+    let ducc = Ducc::new();
+    ducc.load_stdlib(StdlibConfig::new().base64(true)).unwrap();
+    let result: bool = ducc.exec(
+        r#"atob(btoa("hello, world")) === "hello, world""#,
+        None,
+        ExecSettings::default(),
+    ).unwrap();
+    assert!(result);
+}
+
+#[test]
+fn load_stdlib_btoa_invalid_character_is_error() {
+    let ducc = Ducc::new();
+    ducc.load_stdlib(StdlibConfig::new().base64(true)).unwrap();
+    let result: Result<(), _> = ducc.exec("btoa('\\u0100')", None, ExecSettings::default());
+    assert!(result.is_err());
+}
+
 #[test]
 fn user_data_drop() {
     let mut ducc = Ducc::new();
@@ -0,0 +1,190 @@
+use ducc::{Ducc, ExecSettings};
+use error::{Error, ErrorKind, RuntimeError};
+use object::Object;
+use std::error::Error as StdError;
+use std::fmt;
+use value::Value;
+
+#[derive(Debug)]
+struct Root;
+
+impl fmt::Display for Root {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "root cause")
+    }
+}
+
+impl StdError for Root {}
+
+#[derive(Debug)]
+struct Wrapper;
+
+impl RuntimeError for Wrapper {
+    fn message(&self) -> Option<String> {
+        Some("wrapper failed".to_string())
+    }
+
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&ROOT)
+    }
+}
+
+static ROOT: Root = Root;
+
+#[test]
+fn source_chain() {
+    let err = Error::external(Wrapper);
+    let source = StdError::source(&err).expect("expected a source error");
+    assert_eq!(source.to_string(), "root cause");
+}
+
+#[derive(Debug)]
+struct BadResource;
+
+impl RuntimeError for BadResource {
+    fn code(&self) -> ::error::RuntimeErrorCode {
+        ::error::RuntimeErrorCode::Custom("BadResource".to_string())
+    }
+
+    fn message(&self) -> Option<String> {
+        Some("resource is gone".to_string())
+    }
+}
+
+#[test]
+fn custom_error_class_name() {
+    let ducc = Ducc::new();
+    ducc.globals().set("fun", ducc.create_function(|_| -> ::error::Result<()> {
+        Err(Error::external(BadResource))
+    })).unwrap();
+
+    let result: Result<(), Error> = ducc.exec("fun()", None, ExecSettings::default());
+    match result {
+        Err(Error { kind: ErrorKind::RuntimeError { name, .. }, .. }) => {
+            assert_eq!(name, "BadResource");
+        },
+        other => panic!("expected a runtime error, got {:?}", other),
+    }
+}
+
+#[test]
+fn root_cause_reaches_the_bottom() {
+    let err = Error::external(Wrapper);
+    assert_eq!(err.root_cause().to_string(), "root cause");
+}
+
+#[derive(Debug)]
+struct Inner;
+
+impl RuntimeError for Inner {
+    fn message(&self) -> Option<String> {
+        Some("inner failure".to_string())
+    }
+}
+
+#[derive(Debug)]
+struct Outer(Error);
+
+impl fmt::Display for Outer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "outer failure")
+    }
+}
+
+impl StdError for Outer {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl RuntimeError for Outer {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn downcast_ref_recurses_through_rewrapped_errors() {
+    let inner = Error::external(Inner);
+    let outer = Error::external(Outer(inner));
+    assert!(outer.downcast_ref::<Wrapper>().is_none());
+    assert!(outer.downcast_ref::<Inner>().is_some());
+}
+
+#[derive(Debug)]
+struct NotFound;
+
+impl RuntimeError for NotFound {
+    fn message(&self) -> Option<String> {
+        Some("record not found".to_string())
+    }
+
+    fn customize<'ducc>(&self, ducc: &'ducc Ducc, object: &Object<'ducc>) {
+        object.set("errno", 404).unwrap();
+        let _ = ducc;
+    }
+}
+
+#[test]
+fn customize_sets_properties_on_the_thrown_error() {
+    let ducc = Ducc::new();
+    ducc.globals().set("fun", ducc.create_function(|_| -> ::error::Result<()> {
+        Err(Error::external(NotFound))
+    })).unwrap();
+
+    let errno: f64 = ducc.exec(
+        "try { fun() } catch (e) { e.errno }",
+        None,
+        ExecSettings::default(),
+    ).unwrap();
+    assert_eq!(errno, 404.0);
+}
+
+#[test]
+fn runtime_error_captures_stack() {
+    let ducc = Ducc::new();
+    let result: Result<(), Error> = ducc.exec("throw new TypeError('boom')", None, ExecSettings::default());
+    match result {
+        Err(Error { kind: ErrorKind::RuntimeError { name, stack, .. }, .. }) => {
+            assert_eq!(name, "TypeError");
+            assert!(stack.is_some());
+        },
+        other => panic!("expected a runtime error, got {:?}", other),
+    }
+}
+
+#[test]
+fn runtime_error_location_survives_round_trip_through_rust() {
+    let ducc = Ducc::new();
+    let result: Result<(), Error> = ducc.exec("throw new Error('boom')", None, ExecSettings::default());
+    let caught = result.unwrap_err();
+    let original_location = match caught.kind {
+        ErrorKind::RuntimeError { ref location, .. } => location.clone().expect("expected a location"),
+        ref other => panic!("expected a runtime error, got {:?}", other),
+    };
+
+    ducc.globals().set("fun", ducc.create_function(move |_| -> ::error::Result<()> {
+        Err(match caught.kind {
+            ErrorKind::RuntimeError { ref code, ref name, ref stack, ref location, .. } => Error {
+                kind: ErrorKind::RuntimeError {
+                    code: code.clone(),
+                    name: name.clone(),
+                    stack: stack.clone(),
+                    location: location.clone(),
+                },
+                context: caught.context.clone(),
+            },
+            _ => unreachable!(),
+        })
+    })).unwrap();
+
+    let caught_again: Value = ducc.exec(
+        "try { fun() } catch (e) { e }",
+        None,
+        ExecSettings::default(),
+    ).unwrap();
+    let caught_again = caught_again.as_object().unwrap();
+
+    assert_eq!(caught_again.get::<_, String>("fileName").unwrap(), original_location.0);
+    assert_eq!(caught_again.get::<_, f64>("lineNumber").unwrap() as u32, original_location.1);
+}
@@ -0,0 +1,15 @@
+mod array;
+mod array_buffer;
+mod bytes;
+mod conversion;
+mod ducc;
+mod error;
+mod function;
+mod modules;
+mod object;
+#[cfg(feature = "serde")] mod serde_value;
+mod string;
+mod symbol;
+mod typed_array;
+mod user_data;
+mod util;
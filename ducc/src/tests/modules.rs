@@ -0,0 +1,96 @@
+use ducc::{Ducc, ExecSettings, ModuleSource};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn require_source_module() {
+    let mut ducc = Ducc::new();
+    ducc.set_module_resolver(|_, id, _| {
+        assert_eq!(id, "double");
+        Ok(ModuleSource::Source("module.exports = function(n) { return n * 2; };".to_string()))
+    }).unwrap();
+
+    let result: i32 = ducc.exec("require('double')(21)", None, ExecSettings::default()).unwrap();
+    assert_eq!(result, 42);
+}
+
+#[test]
+fn require_native_exports_module() {
+    let mut ducc = Ducc::new();
+    ducc.set_module_resolver(|ducc, id, _| {
+        assert_eq!(id, "greet");
+        let exports = ducc.create_object();
+        exports.set("hello", "world").unwrap();
+        Ok(ModuleSource::Exports(exports))
+    }).unwrap();
+
+    let result: String = ducc.exec("require('greet').hello", None, ExecSettings::default()).unwrap();
+    assert_eq!(result, "world");
+}
+
+#[test]
+fn require_caches_by_id() {
+    let mut ducc = Ducc::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let resolver_calls = calls.clone();
+    ducc.set_module_resolver(move |_, _, _| {
+        resolver_calls.fetch_add(1, Ordering::SeqCst);
+        Ok(ModuleSource::Source("module.exports = {};".to_string()))
+    }).unwrap();
+
+    ducc.exec::<()>("require('a'); require('a'); require('a');", None, ExecSettings::default()).unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn require_threads_requiring_id_to_nested_requires() {
+    let mut ducc = Ducc::new();
+    ducc.set_module_resolver(|_, id, requiring_id| {
+        match id {
+            "parent" => {
+                assert_eq!(requiring_id, None);
+                Ok(ModuleSource::Source("module.exports = require('child');".to_string()))
+            },
+            "child" => {
+                assert_eq!(requiring_id, Some("parent"));
+                Ok(ModuleSource::Source("module.exports = 7;".to_string()))
+            },
+            _ => panic!("unexpected module id: {}", id),
+        }
+    }).unwrap();
+
+    let result: i32 = ducc.exec("require('parent')", None, ExecSettings::default()).unwrap();
+    assert_eq!(result, 7);
+}
+
+#[test]
+fn require_handles_circular_dependencies() {
+    let mut ducc = Ducc::new();
+    ducc.set_module_resolver(|_, id, _| {
+        match id {
+            "a" => Ok(ModuleSource::Source(
+                "exports.fromA = 1; exports.b = require('b');".to_string(),
+            )),
+            "b" => Ok(ModuleSource::Source(
+                "exports.fromB = 2; exports.a = require('a');".to_string(),
+            )),
+            _ => panic!("unexpected module id: {}", id),
+        }
+    }).unwrap();
+
+    // `b`'s `require('a')` hits `a` mid-resolution, so it should see the partially-populated
+    // exports object (just `fromA`, not yet `b`) rather than recursing forever.
+    let a_from_a: i32 = ducc.exec("require('a').fromA", None, ExecSettings::default()).unwrap();
+    assert_eq!(a_from_a, 1);
+    let a_from_b: i32 = ducc.exec("require('b').a.fromA", None, ExecSettings::default()).unwrap();
+    assert_eq!(a_from_b, 1);
+    let b_from_a: i32 = ducc.exec("require('a').b.fromB", None, ExecSettings::default()).unwrap();
+    assert_eq!(b_from_a, 2);
+}
+
+#[test]
+fn require_is_not_a_global_until_a_resolver_is_set() {
+    let ducc = Ducc::new();
+    let result: Result<(), _> = ducc.exec("require('anything')", None, ExecSettings::default());
+    assert!(result.is_err());
+}
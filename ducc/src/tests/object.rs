@@ -1,7 +1,7 @@
 use ducc::Ducc;
 use error::Result;
 use function::Invocation;
-use object::{Object, PropertyDescriptor};
+use object::{EnumOptions, Object, PropertyDescriptor};
 use value::{Value, ToValue};
 
 #[test]
@@ -68,6 +68,26 @@ fn define_prop_error() {
     assert_eq!(vec!["invalid descriptor".to_string()], err.context);
 }
 
+#[test]
+fn property_descriptor_data_constructor() {
+    let ducc = Ducc::new();
+    let object = ducc.create_object();
+
+    let val = 123i8.to_value(&ducc).unwrap();
+    object.define_prop("a", PropertyDescriptor::data(val)).unwrap();
+    assert_eq!(object.get::<_, i8>("a").unwrap(), 123);
+}
+
+#[test]
+fn property_descriptor_accessor_constructor() {
+    let ducc = Ducc::new();
+    let object = ducc.create_object();
+
+    let get = ducc.create_function(|_| Ok(24));
+    object.define_prop("b", PropertyDescriptor::accessor(Some(get), None)).unwrap();
+    assert_eq!(object.get::<_, i8>("b").unwrap(), 24);
+}
+
 #[test]
 fn remove() {
     let ducc = Ducc::new();
@@ -96,6 +116,80 @@ fn call_prop() {
     assert_eq!(number, 579.0f64);
 }
 
+#[test]
+fn get_own_property_descriptor_data() {
+    let ducc = Ducc::new();
+    let object = ducc.create_object();
+    object.set("a", 123).unwrap();
+
+    let desc = object.get_own_property_descriptor("a").unwrap().unwrap();
+    assert!(desc.is_data_descriptor());
+    assert!(!desc.is_accessor_descriptor());
+    assert!(desc.is_writable());
+    assert!(desc.is_enumerable());
+    assert!(desc.is_configurable());
+    assert_eq!(desc.as_value().unwrap().as_number().unwrap(), 123.0);
+}
+
+#[test]
+fn get_own_property_descriptor_accessor() {
+    let ducc = Ducc::new();
+    let object = ducc.create_object();
+    let get = ducc.create_function(|_| Ok(24));
+    object.define_prop("b", PropertyDescriptor::new().getter(get)).unwrap();
+
+    let desc = object.get_own_property_descriptor("b").unwrap().unwrap();
+    assert!(desc.is_accessor_descriptor());
+    assert!(!desc.is_data_descriptor());
+    assert!(desc.as_getter().is_some());
+    assert!(desc.as_setter().is_none());
+}
+
+#[test]
+fn get_own_property_descriptor_missing() {
+    let ducc = Ducc::new();
+    let object = ducc.create_object();
+    assert!(object.get_own_property_descriptor("nope").unwrap().is_none());
+}
+
+#[test]
+fn properties_with_own_only_excludes_inherited() {
+    let ducc = Ducc::new();
+
+    let proto = ducc.create_object();
+    proto.set("inherited", 1).unwrap();
+    let object = ducc.create_object();
+    object.set("own", 2).unwrap();
+    ducc.globals().get::<_, Object>("Object").unwrap()
+        .call_prop::<_, _, Value>("setPrototypeOf", (object.clone(), proto)).unwrap();
+
+    let all: Vec<String> = object.clone().properties::<String, Value>()
+        .map(|p| p.unwrap().0).collect();
+    assert_eq!(all, vec!["own".to_string(), "inherited".to_string()]);
+
+    let own: Vec<String> = object.properties_with::<String, Value>(
+        EnumOptions::new().own_only(true)
+    ).map(|p| p.unwrap().0).collect();
+    assert_eq!(own, vec!["own".to_string()]);
+}
+
+#[test]
+fn properties_with_include_nonenumerable() {
+    let ducc = Ducc::new();
+    let object = ducc.create_object();
+    let val = 123i8.to_value(&ducc).unwrap();
+    object.define_prop("hidden", PropertyDescriptor::new().writable(true).value(val)).unwrap();
+
+    let none: Vec<String> = object.clone().properties::<String, Value>()
+        .map(|p| p.unwrap().0).collect();
+    assert!(none.is_empty());
+
+    let all: Vec<String> = object.properties_with::<String, Value>(
+        EnumOptions::new().include_nonenumerable(true)
+    ).map(|p| p.unwrap().0).collect();
+    assert_eq!(all, vec!["hidden".to_string()]);
+}
+
 #[test]
 fn properties() {
     let ducc = Ducc::new();
@@ -0,0 +1,191 @@
+use ducc::{Ducc, ExecSettings};
+use serde_value::DeserializeOptions;
+use value::Value;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Config {
+    name: String,
+    retries: u32,
+    tags: Vec<String>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct NameOnly {
+    name: String,
+}
+
+#[test]
+fn to_value_serde_struct() {
+    let ducc = Ducc::new();
+    let config = Config {
+        name: "worker".to_string(),
+        retries: 3,
+        tags: vec!["a".to_string(), "b".to_string()],
+    };
+    let value = ducc.to_value_serde(&config).unwrap();
+    let object = value.as_object().unwrap();
+    assert_eq!(object.get::<_, String>("name").unwrap(), "worker");
+    assert_eq!(object.get::<_, u32>("retries").unwrap(), 3);
+    assert_eq!(object.get::<_, Vec<String>>("tags").unwrap(), vec!["a", "b"]);
+}
+
+#[test]
+fn from_value_serde_struct() {
+    let ducc = Ducc::new();
+    let object = ducc.create_object();
+    object.set("name", "worker").unwrap();
+    object.set("retries", 3).unwrap();
+    object.set("tags", vec!["a", "b"]).unwrap();
+    let config: Config = ducc.from_value_serde(Value::Object(object)).unwrap();
+    assert_eq!(config, Config {
+        name: "worker".to_string(),
+        retries: 3,
+        tags: vec!["a".to_string(), "b".to_string()],
+    });
+}
+
+#[test]
+fn serde_round_trip_through_script() {
+    let ducc = Ducc::new();
+    let config = Config {
+        name: "worker".to_string(),
+        retries: 3,
+        tags: vec!["a".to_string()],
+    };
+    let value = ducc.to_value_serde(&config).unwrap();
+    ducc.globals().set("config", value).unwrap();
+    let retries: u32 = ducc.exec(
+        "config.retries += 1; config.retries",
+        None,
+        ExecSettings::default(),
+    ).unwrap();
+    assert_eq!(retries, 4);
+
+    let value: Value = ducc.globals().get("config").unwrap();
+    let round_tripped: Config = ducc.from_value_serde(value).unwrap();
+    assert_eq!(round_tripped, Config {
+        name: "worker".to_string(),
+        retries: 4,
+        tags: vec!["a".to_string()],
+    });
+}
+
+#[test]
+fn from_value_serde_integer_types() {
+    let ducc = Ducc::new();
+    assert_eq!(ducc.from_value_serde::<i8>(Value::Number(-42.0)).unwrap(), -42i8);
+    assert_eq!(ducc.from_value_serde::<u32>(Value::Number(42.0)).unwrap(), 42u32);
+    assert_eq!(ducc.from_value_serde::<i64>(Value::Number(42.0)).unwrap(), 42i64);
+    assert_eq!(ducc.from_value_serde::<u64>(Value::Number(42.0)).unwrap(), 42u64);
+    assert_eq!(ducc.from_value_serde::<i128>(Value::Number(42.0)).unwrap(), 42i128);
+    assert_eq!(ducc.from_value_serde::<u128>(Value::Number(42.0)).unwrap(), 42u128);
+}
+
+#[test]
+fn from_value_serde_integer_out_of_range_is_error() {
+    let ducc = Ducc::new();
+    assert!(ducc.from_value_serde::<u8>(Value::Number(256.0)).is_err());
+    assert!(ducc.from_value_serde::<i8>(Value::Number(-129.0)).is_err());
+}
+
+#[test]
+fn from_value_serde_integer_with_fraction_is_error() {
+    let ducc = Ducc::new();
+    assert!(ducc.from_value_serde::<u32>(Value::Number(1.5)).is_err());
+}
+
+#[test]
+fn serde_i64_round_trips_losslessly_beyond_safe_range() {
+    let ducc = Ducc::new();
+    let value = i64::max_value();
+    let serialized = ducc.to_value_serde(&value).unwrap();
+    assert_eq!(ducc.from_value_serde::<i64>(serialized).unwrap(), value);
+}
+
+#[test]
+fn serde_u64_round_trips_losslessly_beyond_safe_range() {
+    let ducc = Ducc::new();
+    let value = u64::max_value();
+    let serialized = ducc.to_value_serde(&value).unwrap();
+    assert_eq!(ducc.from_value_serde::<u64>(serialized).unwrap(), value);
+}
+
+#[test]
+fn from_value_serde_float_types() {
+    let ducc = Ducc::new();
+    assert_eq!(ducc.from_value_serde::<f32>(Value::Number(1.5)).unwrap(), 1.5f32);
+    assert_eq!(ducc.from_value_serde::<f64>(Value::Number(1.5)).unwrap(), 1.5f64);
+}
+
+#[test]
+fn from_value_serde_ignores_unknown_fields_without_materializing_them() {
+    let ducc = Ducc::new();
+    let object: Value = ducc.exec(
+        "({
+            name: 'worker',
+            tags: ['a', 'b', 'c'],
+            nested: { a: { b: { c: [1, 2, 3] } } },
+            handler: function() {},
+        })",
+        None,
+        ExecSettings::default(),
+    ).unwrap();
+    let result: NameOnly = ducc.from_value_serde(object).unwrap();
+    assert_eq!(result, NameOnly { name: "worker".to_string() });
+}
+
+#[test]
+fn from_value_serde_with_defaults_matches_from_value_serde() {
+    let ducc = Ducc::new();
+    let function = ducc.exec::<Value>("(function() {})", None, ExecSettings::default()).unwrap();
+    let result: () = ducc
+        .from_value_serde_with(function, DeserializeOptions::default())
+        .unwrap();
+    assert_eq!(result, ());
+}
+
+#[test]
+fn from_value_serde_with_error_on_undeserializable() {
+    let ducc = Ducc::new();
+    let function = ducc.exec::<Value>("(function() {})", None, ExecSettings::default()).unwrap();
+    let options = DeserializeOptions { error_on_undeserializable: true, ..DeserializeOptions::default() };
+    let result: Result<(), _> = ducc.from_value_serde_with(function, options);
+    assert!(result.is_err());
+}
+
+#[test]
+fn from_value_serde_with_undefined_as_none_affects_bare_values() {
+    let ducc = Ducc::new();
+    // By default, a bare `undefined` deserializes as a unit value.
+    let unit: () = ducc.from_value_serde(Value::Undefined).unwrap();
+    assert_eq!(unit, ());
+
+    // With `undefined_as_none` set, the same bare `undefined` is instead treated like `null`,
+    // which a plain `()` target can't accept.
+    let options = DeserializeOptions { undefined_as_none: true, ..DeserializeOptions::default() };
+    let result: Result<(), _> = ducc.from_value_serde_with(Value::Undefined, options);
+    assert!(result.is_err());
+}
+
+#[test]
+fn from_value_serde_with_allow_trailing_elements() {
+    let ducc = Ducc::new();
+    let array = ducc.create_array();
+    array.push("worker").unwrap();
+    array.push(3u32).unwrap();
+    array.push("extra").unwrap();
+    let options = DeserializeOptions { allow_trailing_elements: true, ..DeserializeOptions::default() };
+    let result: (String, u32) = ducc.from_value_serde_with(Value::Array(array), options).unwrap();
+    assert_eq!(result, ("worker".to_string(), 3));
+}
+
+#[test]
+fn from_value_serde_rejects_trailing_elements_by_default() {
+    let ducc = Ducc::new();
+    let array = ducc.create_array();
+    array.push("worker").unwrap();
+    array.push(3u32).unwrap();
+    array.push("extra").unwrap();
+    let result: Result<(String, u32), _> = ducc.from_value_serde(Value::Array(array));
+    assert!(result.is_err());
+}
@@ -0,0 +1,44 @@
+use ducc::{Ducc, ExecSettings};
+use value::Value;
+
+#[test]
+fn create_symbol_round_trips_through_value() {
+    let ducc = Ducc::new();
+    let symbol = ducc.create_symbol(Some("tag")).unwrap();
+    ducc.globals().set("s", symbol).unwrap();
+    let value: Value = ducc.globals().get("s").unwrap();
+    assert!(value.is_symbol());
+}
+
+#[test]
+fn create_symbol_without_description() {
+    let ducc = Ducc::new();
+    assert!(ducc.create_symbol(None).is_ok());
+}
+
+#[test]
+fn symbol_usable_as_property_key() {
+    let ducc = Ducc::new();
+    let object = ducc.create_object();
+    let symbol = ducc.create_symbol(Some("key")).unwrap();
+    object.set(symbol.clone(), "value").unwrap();
+    let value: String = object.get(symbol).unwrap();
+    assert_eq!(value, "value");
+}
+
+#[test]
+fn well_known_symbols_are_retrievable() {
+    let ducc = Ducc::new();
+    assert!(ducc.symbol_iterator().is_ok());
+    assert!(ducc.symbol_async_iterator().is_ok());
+    assert!(ducc.symbol_to_string_tag().is_ok());
+}
+
+#[test]
+fn symbol_iterator_matches_global() {
+    let ducc = Ducc::new();
+    let symbol = ducc.symbol_iterator().unwrap();
+    ducc.globals().set("s", symbol).unwrap();
+    let matches: bool = ducc.exec("s === Symbol.iterator", None, ExecSettings::default()).unwrap();
+    assert!(matches);
+}
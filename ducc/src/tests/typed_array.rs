@@ -0,0 +1,67 @@
+use ducc::Ducc;
+use typed_array::{TypedArrayKind, TypedSlice};
+use value::{FromValue, ToValue};
+
+#[test]
+fn to_vec_f64() {
+    let ducc = Ducc::new();
+    let array = ducc.create_typed_array(TypedArrayKind::Float64, &[1.0, 2.0, 3.0]).unwrap();
+    assert_eq!(array.kind(), TypedArrayKind::Float64);
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.to_vec_f64().unwrap(), vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn to_vec_i32() {
+    let ducc = Ducc::new();
+    let array = ducc.create_typed_array(TypedArrayKind::Int32, &[-1, 2, -3]).unwrap();
+    assert_eq!(array.to_vec_i32().unwrap(), vec![-1, 2, -3]);
+}
+
+#[test]
+fn wrong_kind_is_error() {
+    let ducc = Ducc::new();
+    let array = ducc.create_typed_array(TypedArrayKind::Int32, &[1, 2, 3]).unwrap();
+    assert!(array.to_vec_f64().is_err());
+}
+
+#[test]
+fn round_trip_through_value() {
+    let ducc = Ducc::new();
+    ducc.globals().set("arr", ducc.create_typed_array(TypedArrayKind::Uint16, &[10u16, 20, 30]).unwrap()).unwrap();
+    let result: f64 = ducc.exec("arr[1] + arr.length", None, Default::default()).unwrap();
+    assert_eq!(result, 23.0);
+}
+
+#[test]
+fn typed_slice_round_trips_as_a_typed_array() {
+    let ducc = Ducc::new();
+    let value = TypedSlice(vec![1.0f32, 2.0, 3.0]).to_value(&ducc).unwrap();
+    assert_eq!(value.type_name(), "typed array");
+    assert_eq!(TypedSlice::from_value(value, &ducc).unwrap().into_vec(), vec![1.0f32, 2.0, 3.0]);
+}
+
+#[test]
+fn typed_slice_preserves_element_kind() {
+    let ducc = Ducc::new();
+    ducc.globals().set("arr", TypedSlice(vec![1i32, -2, 3])).unwrap();
+    let kind: String = ducc.exec("arr.constructor.name", None, Default::default()).unwrap();
+    assert_eq!(kind, "Int32Array");
+}
+
+#[test]
+fn as_slice_reads_without_copying() {
+    let ducc = Ducc::new();
+    let array = ducc.create_typed_array(TypedArrayKind::Uint8, &[1u8, 2, 3]).unwrap();
+    assert_eq!(array.as_slice(), &[1, 2, 3]);
+}
+
+#[test]
+fn as_mut_slice_writes_are_visible_to_script() {
+    let ducc = Ducc::new();
+    let mut array = ducc.create_typed_array(TypedArrayKind::Uint8, &[0u8, 0, 0]).unwrap();
+    array.as_mut_slice().copy_from_slice(&[9, 8, 7]);
+    ducc.globals().set("arr", array).unwrap();
+    let result: f64 = ducc.exec("arr[0] + arr[1] + arr[2]", None, Default::default()).unwrap();
+    assert_eq!(result, 24.0);
+}
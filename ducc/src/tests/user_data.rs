@@ -0,0 +1,32 @@
+use ducc::Ducc;
+use user_data::AnyUserData;
+
+#[test]
+fn borrow() {
+    let ducc = Ducc::new();
+    let data = ducc.create_user_data(123i32);
+    assert_eq!(*data.borrow::<i32>().unwrap(), 123);
+}
+
+#[test]
+fn borrow_mut() {
+    let ducc = Ducc::new();
+    let data = ducc.create_user_data(123i32);
+    *data.borrow_mut::<i32>().unwrap() += 1;
+    assert_eq!(*data.borrow::<i32>().unwrap(), 124);
+}
+
+#[test]
+fn wrong_type_is_error() {
+    let ducc = Ducc::new();
+    let data = ducc.create_user_data(123i32);
+    assert!(data.borrow::<String>().is_err());
+}
+
+#[test]
+fn round_trip_through_value() {
+    let ducc = Ducc::new();
+    ducc.globals().set("data", ducc.create_user_data(123i32)).unwrap();
+    let data: AnyUserData = ducc.globals().get("data").unwrap();
+    assert_eq!(*data.borrow::<i32>().unwrap(), 123);
+}
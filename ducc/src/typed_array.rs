@@ -0,0 +1,336 @@
+use error::{Error, Result};
+use ffi;
+use object::Object;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::slice;
+use std::str;
+use types::Ref;
+use util::{protect_duktape_closure, push_bytes};
+
+/// The element kind of a `TypedArray`, mirroring JavaScript's typed array view classes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TypedArrayKind {
+    Int8,
+    Uint8,
+    Uint8Clamped,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Float32,
+    Float64,
+}
+
+impl TypedArrayKind {
+    /// The size, in bytes, of a single element of this kind.
+    pub fn element_size(self) -> usize {
+        match self {
+            TypedArrayKind::Int8 => 1,
+            TypedArrayKind::Uint8 => 1,
+            TypedArrayKind::Uint8Clamped => 1,
+            TypedArrayKind::Int16 => 2,
+            TypedArrayKind::Uint16 => 2,
+            TypedArrayKind::Int32 => 4,
+            TypedArrayKind::Uint32 => 4,
+            TypedArrayKind::Float32 => 4,
+            TypedArrayKind::Float64 => 8,
+        }
+    }
+
+    fn bufobj_flags(self) -> ffi::duk_uint_t {
+        match self {
+            TypedArrayKind::Int8 => ffi::DUK_BUFOBJ_INT8ARRAY,
+            TypedArrayKind::Uint8 => ffi::DUK_BUFOBJ_UINT8ARRAY,
+            TypedArrayKind::Uint8Clamped => ffi::DUK_BUFOBJ_UINT8CLAMPEDARRAY,
+            TypedArrayKind::Int16 => ffi::DUK_BUFOBJ_INT16ARRAY,
+            TypedArrayKind::Uint16 => ffi::DUK_BUFOBJ_UINT16ARRAY,
+            TypedArrayKind::Int32 => ffi::DUK_BUFOBJ_INT32ARRAY,
+            TypedArrayKind::Uint32 => ffi::DUK_BUFOBJ_UINT32ARRAY,
+            TypedArrayKind::Float32 => ffi::DUK_BUFOBJ_FLOAT32ARRAY,
+            TypedArrayKind::Float64 => ffi::DUK_BUFOBJ_FLOAT64ARRAY,
+        }
+    }
+}
+
+/// Reference to a JavaScript typed array view (`Int8Array`, `Float64Array`, etc.), offering
+/// zero-copy access to the underlying buffer.
+#[derive(Clone, Debug)]
+pub struct TypedArray<'ducc> {
+    pub(crate) ref_: Ref<'ducc>,
+    kind: TypedArrayKind,
+}
+
+impl<'ducc> TypedArray<'ducc> {
+    pub(crate) fn new(ref_: Ref<'ducc>, kind: TypedArrayKind) -> TypedArray<'ducc> {
+        TypedArray { ref_, kind }
+    }
+
+    /// Consumes the typed array and returns it as a JavaScript object. This is inexpensive, since
+    /// a typed array *is* an object.
+    pub fn into_object(self) -> Object<'ducc> {
+        Object(self.ref_)
+    }
+
+    /// Returns the element kind of this view.
+    pub fn kind(&self) -> TypedArrayKind {
+        self.kind
+    }
+
+    /// Returns the number of elements in this view.
+    pub fn len(&self) -> usize {
+        self.byte_len() / self.kind.element_size()
+    }
+
+    /// Returns the byte offset of this view into its underlying buffer.
+    pub fn byte_offset(&self) -> usize {
+        let ducc = self.ref_.ducc;
+        unsafe {
+            assert_stack!(ducc.ctx, 0, {
+                ducc.push_ref(&self.ref_);
+                ffi::duk_get_prop_string(ducc.ctx, -1, cstr!("byteOffset"));
+                let offset = ffi::duk_get_uint(ducc.ctx, -1);
+                ffi::duk_pop_2(ducc.ctx);
+                offset as usize
+            })
+        }
+    }
+
+    fn byte_len(&self) -> usize {
+        let ducc = self.ref_.ducc;
+        unsafe {
+            assert_stack!(ducc.ctx, 0, {
+                ducc.push_ref(&self.ref_);
+                assert!(ffi::duk_is_buffer_data(ducc.ctx, -1) != 0);
+                let mut len = 0;
+                ffi::duk_get_buffer_data(ducc.ctx, -1, &mut len);
+                ffi::duk_pop(ducc.ctx);
+                len as usize
+            })
+        }
+    }
+
+    /// Returns a byte-level view directly into the underlying buffer, without copying.
+    ///
+    /// The returned slice is only valid for as long as `self` is not mutated through `as_mut_slice`
+    /// and the buffer it refers to is not detached; Duktape's fixed buffers are never moved or
+    /// freed out from under a live reference, so this is otherwise safe to hold on to.
+    pub fn as_slice(&self) -> &[u8] {
+        let ducc = self.ref_.ducc;
+        unsafe {
+            assert_stack!(ducc.ctx, 0, {
+                ducc.push_ref(&self.ref_);
+                assert!(ffi::duk_is_buffer_data(ducc.ctx, -1) != 0);
+                let mut len = 0;
+                let data = ffi::duk_get_buffer_data(ducc.ctx, -1, &mut len);
+                ffi::duk_pop(ducc.ctx);
+                if data.is_null() { &[] } else { slice::from_raw_parts(data as *const u8, len as usize) }
+            })
+        }
+    }
+
+    /// Returns a mutable byte-level view directly into the underlying buffer, without copying.
+    ///
+    /// See `as_slice` for the lifetime and aliasing caveats; in particular, other `TypedArray`
+    /// handles (including JavaScript-side views) that alias the same buffer can observe writes made
+    /// through this slice and vice versa.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        let ducc = self.ref_.ducc;
+        unsafe {
+            assert_stack!(ducc.ctx, 0, {
+                ducc.push_ref(&self.ref_);
+                assert!(ffi::duk_is_buffer_data(ducc.ctx, -1) != 0);
+                let mut len = 0;
+                let data = ffi::duk_get_buffer_data(ducc.ctx, -1, &mut len);
+                ffi::duk_pop(ducc.ctx);
+                if data.is_null() {
+                    &mut []
+                } else {
+                    slice::from_raw_parts_mut(data as *mut u8, len as usize)
+                }
+            })
+        }
+    }
+
+    fn raw_data(&self) -> Vec<u8> {
+        let ducc = self.ref_.ducc;
+        unsafe {
+            assert_stack!(ducc.ctx, 0, {
+                ducc.push_ref(&self.ref_);
+                assert!(ffi::duk_is_buffer_data(ducc.ctx, -1) != 0);
+                let mut len = 0;
+                let data = ffi::duk_get_buffer_data(ducc.ctx, -1, &mut len);
+                assert!(!data.is_null());
+                let bytes = slice::from_raw_parts(data as *const u8, len as usize);
+                let owned = bytes.to_vec();
+                ffi::duk_pop(ducc.ctx);
+                owned
+            })
+        }
+    }
+
+    fn to_vec_of<T: Copy>(&self, kind: TypedArrayKind) -> Result<Vec<T>> {
+        if self.kind != kind {
+            return Err(Error::from_js_conversion("TypedArray", "Vec"));
+        }
+
+        // `data` is a `Vec<u8>`, which only guarantees alignment 1, but `T` may require a stricter
+        // alignment (e.g. `i32`, `f64`), so each element must be read with an unaligned load rather
+        // than reinterpreted via `slice::from_raw_parts`, which requires a `T`-aligned pointer.
+        let data = self.raw_data();
+        let len = data.len() / mem::size_of::<T>();
+        let ptr = data.as_ptr() as *const T;
+        Ok((0..len).map(|i| unsafe { ptr::read_unaligned(ptr.add(i)) }).collect())
+    }
+
+    /// Extracts this view's elements into a `Vec<i8>`, returning an error if its `kind()` is not
+    /// `TypedArrayKind::Int8`.
+    pub fn to_vec_i8(&self) -> Result<Vec<i8>> {
+        self.to_vec_of(TypedArrayKind::Int8)
+    }
+
+    /// Extracts this view's elements into a `Vec<u8>`, returning an error if its `kind()` is not
+    /// `TypedArrayKind::Uint8` or `TypedArrayKind::Uint8Clamped`.
+    pub fn to_vec_u8(&self) -> Result<Vec<u8>> {
+        match self.kind {
+            TypedArrayKind::Uint8 | TypedArrayKind::Uint8Clamped => {
+                Ok(self.raw_data())
+            }
+            _ => Err(Error::from_js_conversion("TypedArray", "Vec")),
+        }
+    }
+
+    /// Extracts this view's elements into a `Vec<i16>`, returning an error if its `kind()` is not
+    /// `TypedArrayKind::Int16`.
+    pub fn to_vec_i16(&self) -> Result<Vec<i16>> {
+        self.to_vec_of(TypedArrayKind::Int16)
+    }
+
+    /// Extracts this view's elements into a `Vec<u16>`, returning an error if its `kind()` is not
+    /// `TypedArrayKind::Uint16`.
+    pub fn to_vec_u16(&self) -> Result<Vec<u16>> {
+        self.to_vec_of(TypedArrayKind::Uint16)
+    }
+
+    /// Extracts this view's elements into a `Vec<i32>`, returning an error if its `kind()` is not
+    /// `TypedArrayKind::Int32`.
+    pub fn to_vec_i32(&self) -> Result<Vec<i32>> {
+        self.to_vec_of(TypedArrayKind::Int32)
+    }
+
+    /// Extracts this view's elements into a `Vec<u32>`, returning an error if its `kind()` is not
+    /// `TypedArrayKind::Uint32`.
+    pub fn to_vec_u32(&self) -> Result<Vec<u32>> {
+        self.to_vec_of(TypedArrayKind::Uint32)
+    }
+
+    /// Extracts this view's elements into a `Vec<f32>`, returning an error if its `kind()` is not
+    /// `TypedArrayKind::Float32`.
+    pub fn to_vec_f32(&self) -> Result<Vec<f32>> {
+        self.to_vec_of(TypedArrayKind::Float32)
+    }
+
+    /// Extracts this view's elements into a `Vec<f64>`, returning an error if its `kind()` is not
+    /// `TypedArrayKind::Float64`.
+    pub fn to_vec_f64(&self) -> Result<Vec<f64>> {
+        self.to_vec_of(TypedArrayKind::Float64)
+    }
+}
+
+// Inspects the object at `idx` (which must already be known to be buffer-backed, via
+// `duk_is_buffer_data`) and maps its `constructor.name` back to a `TypedArrayKind`. Returns `None`
+// for buffer-backed objects that aren't one of the nine typed array view classes (for example,
+// `ArrayBuffer` or `DataView`).
+pub(crate) unsafe fn typed_array_kind_of(
+    ctx: *mut ffi::duk_context,
+    idx: ffi::duk_idx_t,
+) -> Option<TypedArrayKind> {
+    let idx = ffi::duk_normalize_index(ctx, idx);
+
+    assert_stack!(ctx, 0, {
+        ffi::duk_require_stack(ctx, 2);
+        ffi::duk_get_prop_string(ctx, idx, cstr!("constructor"));
+        ffi::duk_get_prop_string(ctx, -1, cstr!("name"));
+        let mut len = 0;
+        let ptr = ffi::duk_get_lstring(ctx, -1, &mut len);
+        let kind = if ptr.is_null() {
+            None
+        } else {
+            let bytes = slice::from_raw_parts(ptr as *const u8, len as usize);
+            match str::from_utf8(bytes) {
+                Ok("Int8Array") => Some(TypedArrayKind::Int8),
+                Ok("Uint8Array") => Some(TypedArrayKind::Uint8),
+                Ok("Uint8ClampedArray") => Some(TypedArrayKind::Uint8Clamped),
+                Ok("Int16Array") => Some(TypedArrayKind::Int16),
+                Ok("Uint16Array") => Some(TypedArrayKind::Uint16),
+                Ok("Int32Array") => Some(TypedArrayKind::Int32),
+                Ok("Uint32Array") => Some(TypedArrayKind::Uint32),
+                Ok("Float32Array") => Some(TypedArrayKind::Float32),
+                Ok("Float64Array") => Some(TypedArrayKind::Float64),
+                _ => None,
+            }
+        };
+        ffi::duk_pop_2(ctx);
+        kind
+    })
+}
+
+pub(crate) unsafe fn push_typed_array(
+    ctx: *mut ffi::duk_context,
+    kind: TypedArrayKind,
+    bytes: &[u8],
+) -> Result<()> {
+    push_bytes(ctx, bytes)?;
+    protect_duktape_closure(ctx, 1, 1, |ctx| {
+        ffi::duk_push_buffer_object(
+            ctx,
+            -1,
+            0,
+            bytes.len() as ffi::duk_size_t,
+            kind.bufobj_flags(),
+        );
+        ffi::duk_remove(ctx, -2);
+    })
+}
+
+/// A thin wrapper around `Vec<T>` that, unlike a bare `Vec<T>` (which always boxes its elements
+/// into a plain JavaScript `Array`), converts directly to and from a JavaScript typed array view
+/// through `ToValue`/`FromValue` impls, for every `T` with a matching `TypedArrayKind` (`i8`,
+/// `u8`, `i16`, `u16`, `i32`, `u32`, `f32`, and `f64`). The elements are copied in bulk in each
+/// direction: `to_value` copies them into a freshly allocated buffer, and `from_value` copies them
+/// out into a new `Vec<T>`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TypedSlice<T>(pub Vec<T>);
+
+impl<T> TypedSlice<T> {
+    /// Wraps `vec` so that it converts through a typed array view.
+    pub fn new(vec: Vec<T>) -> TypedSlice<T> {
+        TypedSlice(vec)
+    }
+
+    /// Unwraps this into the underlying `Vec<T>`.
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> From<Vec<T>> for TypedSlice<T> {
+    fn from(vec: Vec<T>) -> TypedSlice<T> {
+        TypedSlice(vec)
+    }
+}
+
+impl<T> Deref for TypedSlice<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for TypedSlice<T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        &mut self.0
+    }
+}
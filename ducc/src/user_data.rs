@@ -0,0 +1,90 @@
+use ffi;
+use error::{Error, Result};
+use object::Object;
+use std::any::Any;
+use std::cell::{Ref as CellRef, RefCell, RefMut as CellRefMut};
+use types::Ref;
+
+const DATA: [i8; 5] = hidden_i8str!('d', 'a', 't', 'a');
+
+/// Reference to an opaque Rust value of some type `T` that has been handed to JavaScript by
+/// `Ducc::create_user_data`. JavaScript only ever sees an empty, otherwise inert object; the boxed
+/// value is recovered on the Rust side via `borrow`/`borrow_mut`.
+#[derive(Clone, Debug)]
+pub struct AnyUserData<'ducc>(pub(crate) Ref<'ducc>);
+
+impl<'ducc> AnyUserData<'ducc> {
+    /// Consumes the user data and returns it as a JavaScript object. This is inexpensive, since a
+    /// user data value *is* an object.
+    pub fn into_object(self) -> Object<'ducc> {
+        Object(self.0)
+    }
+
+    /// Borrows the underlying value, returning an error if `T` does not match the type that was
+    /// originally passed to `Ducc::create_user_data`, or if the value is currently mutably
+    /// borrowed.
+    pub fn borrow<T: 'static>(&self) -> Result<CellRef<T>> {
+        self.cell::<T>()?.try_borrow().map_err(|_| Error::recursive_mut_callback())
+    }
+
+    /// Mutably borrows the underlying value, returning an error if `T` does not match the type
+    /// that was originally passed to `Ducc::create_user_data`, or if the value is currently
+    /// borrowed.
+    pub fn borrow_mut<T: 'static>(&self) -> Result<CellRefMut<T>> {
+        self.cell::<T>()?.try_borrow_mut().map_err(|_| Error::recursive_mut_callback())
+    }
+
+    fn cell<T: 'static>(&self) -> Result<&RefCell<T>> {
+        self.any().downcast_ref::<RefCell<T>>()
+            .ok_or_else(|| Error::from_js_conversion("UserData", "T"))
+    }
+
+    fn any(&self) -> &Any {
+        let ducc = self.0.ducc;
+        unsafe {
+            assert_stack!(ducc.ctx, 0, {
+                ducc.push_ref(&self.0);
+                ffi::duk_get_prop_string(ducc.ctx, -1, DATA.as_ptr() as *const _);
+                let boxed = ffi::duk_get_pointer(ducc.ctx, -1) as *mut Box<Any>;
+                ffi::duk_pop_2(ducc.ctx);
+                &**(&*boxed)
+            })
+        }
+    }
+}
+
+unsafe extern "C" fn finalizer(ctx: *mut ffi::duk_context) -> ffi::duk_ret_t {
+    ffi::duk_require_stack(ctx, 1);
+    ffi::duk_get_prop_string(ctx, 0, DATA.as_ptr() as *const _);
+    let boxed = Box::from_raw(ffi::duk_get_pointer(ctx, -1) as *mut Box<Any>);
+    drop(boxed);
+    ffi::duk_pop(ctx);
+    ffi::duk_push_undefined(ctx);
+    ffi::duk_put_prop_string(ctx, 0, DATA.as_ptr() as *const _);
+    0
+}
+
+pub(crate) unsafe fn push_user_data<T: 'static>(ctx: *mut ffi::duk_context, value: T) {
+    ffi::duk_require_stack(ctx, 2);
+    ffi::duk_push_object(ctx);
+    let boxed: Box<Any> = Box::new(RefCell::new(value));
+    ffi::duk_push_pointer(ctx, Box::into_raw(Box::new(boxed)) as *mut _);
+    ffi::duk_put_prop_string(ctx, -2, DATA.as_ptr() as *const _);
+    ffi::duk_push_c_function(ctx, Some(finalizer), 1);
+    ffi::duk_set_finalizer(ctx, -2);
+}
+
+// Inspects the object at `idx` (which must already be known to be a plain object) and returns
+// `true` if it was created by `push_user_data` (detected by the presence of its hidden data
+// pointer property).
+pub(crate) unsafe fn is_user_data(ctx: *mut ffi::duk_context, idx: ffi::duk_idx_t) -> bool {
+    let idx = ffi::duk_normalize_index(ctx, idx);
+
+    assert_stack!(ctx, 0, {
+        ffi::duk_require_stack(ctx, 1);
+        ffi::duk_get_prop_string(ctx, idx, DATA.as_ptr() as *const _);
+        let is_user_data = !ffi::duk_get_pointer(ctx, -1).is_null();
+        ffi::duk_pop(ctx);
+        is_user_data
+    })
+}
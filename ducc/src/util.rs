@@ -1,12 +1,16 @@
 use cesu8::{from_cesu8, to_cesu8};
 use error::{Error, ErrorKind, Result, RuntimeErrorCode};
 use ffi;
+use object::Object;
+use std::alloc::{GlobalAlloc, Layout, System};
 use std::ffi::{CStr, CString};
 use std::os::raw::{c_char, c_void};
 use std::{process, ptr, slice};
-use std::sync::{Once, ONCE_INIT};
+use std::sync::{Arc, Once, ONCE_INIT};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 use types::AnyMap;
+use ducc::{Ducc, ExecSettings};
 
 // Throws an error if `$body` results in a change of `$ctx`'s stack size that isn't exactly equal to
 // `$diff`. Must be used in an `unsafe` block.
@@ -122,7 +126,9 @@ unsafe extern "C" fn error_finalizer(ctx: *mut ffi::duk_context) -> ffi::duk_ret
     0
 }
 
-pub(crate) unsafe fn push_error(ctx: *mut ffi::duk_context, error: Error) {
+pub(crate) unsafe fn push_error(ducc: &Ducc, error: Error) {
+    let ctx = ducc.ctx;
+
     assert_stack!(ctx, 1, {
         let desc = error.into_runtime_error_desc();
         let cstr_msg = match desc.message {
@@ -136,14 +142,22 @@ pub(crate) unsafe fn push_error(ctx: *mut ffi::duk_context, error: Error) {
             Ok(name) => name,
             Err(_) => CString::new("Error").unwrap(),
         };
+        let cstr_file_name = match desc.location {
+            Some((ref file_name, _)) => CString::new(to_cesu8(file_name)).ok(),
+            None => None,
+        };
+        let line_number = desc.location.as_ref().map_or(0, |&(_, line)| line as ffi::duk_int_t);
+        let file_name_ptr = match cstr_file_name {
+            Some(ref file_name) => file_name.as_ptr(),
+            None => ptr::null(),
+        };
 
         ffi::duk_require_stack(ctx, 2);
         ffi::duk_push_error_object_raw(
             ctx,
             desc.code.to_duk_errcode(),
-            // TODO: Line number and file name:
-            ptr::null_mut(),
-            0,
+            file_name_ptr,
+            line_number,
             ptr::null_mut(),
         );
 
@@ -153,6 +167,13 @@ pub(crate) unsafe fn push_error(ctx: *mut ffi::duk_context, error: Error) {
             ffi::duk_push_lstring(ctx, cstr_msg.as_ptr(), cstr_msg.as_bytes().len());
             ffi::duk_put_prop_string(ctx, -2, cstr!("message"));
         }
+
+        if let ErrorKind::ExternalError(ref err) = desc.cause.kind {
+            ffi::duk_dup(ctx, -1);
+            let object = Object(ducc.pop_ref());
+            err.customize(ducc, &object);
+        }
+
         ffi::duk_push_pointer(ctx, Box::into_raw(desc.cause) as *mut _);
         ffi::duk_put_prop_string(ctx, -2, ERROR_KEY.as_ptr());
         ffi::duk_push_c_function(ctx, Some(error_finalizer), 1);
@@ -183,6 +204,15 @@ pub(crate) unsafe fn pop_error(ctx: *mut ffi::duk_context) -> Error {
         ffi::duk_get_prop_string(ctx, -1, cstr!("message"));
         let message = get_string(ctx, -1);
         ffi::duk_pop(ctx);
+        ffi::duk_get_prop_string(ctx, -1, cstr!("stack"));
+        let stack = get_string(ctx, -1);
+        ffi::duk_pop(ctx);
+        ffi::duk_get_prop_string(ctx, -1, cstr!("fileName"));
+        let file_name = get_string(ctx, -1);
+        ffi::duk_pop(ctx);
+        ffi::duk_get_prop_string(ctx, -1, cstr!("lineNumber"));
+        let line_number = ffi::duk_get_number_default(ctx, -1, 0.0);
+        ffi::duk_pop(ctx);
 
         let name = match name.is_empty() {
             false => name,
@@ -194,11 +224,21 @@ pub(crate) unsafe fn pop_error(ctx: *mut ffi::duk_context) -> Error {
             true => None,
         };
 
+        let stack = match stack.is_empty() {
+            false => Some(stack),
+            true => None,
+        };
+
+        let location = match file_name.is_empty() {
+            false => Some((file_name, line_number as u32)),
+            true => None,
+        };
+
         ffi::duk_pop(ctx);
 
         Error {
-            kind: ErrorKind::RuntimeError { code, name },
-            context: message,
+            kind: ErrorKind::RuntimeError { code, name, stack, location },
+            context: message.into_iter().collect(),
         }
     })
 }
@@ -252,8 +292,34 @@ pub(crate) unsafe fn create_heap() -> *mut ffi::duk_context {
         ensure_exec_timeout_check_exists();
     }
 
-    let udata = Box::into_raw(Box::new(Udata { timeout: None }));
-    let ctx = ffi::duk_create_heap(None, None, None, udata as *mut _, Some(fatal_handler));
+    let udata = Box::into_raw(Box::new(Udata {
+        timeout: None,
+        cancel_fn: None,
+        interrupt: Arc::new(AtomicBool::new(false)),
+        interrupt_fn: None,
+        bytes_allocated: 0,
+        peak_bytes_allocated: 0,
+        memory_limit: None,
+        persistent_memory_limit: None,
+    }));
+    // The tracking allocator backs `Ducc::set_memory_limit`/`ExecSettings::memory_limit` and the
+    // `memory_usage`/`peak_memory_usage` getters, but it costs a `System`-allocator round trip plus
+    // an `ALLOC_HEADER_SIZE`-byte prefix on every single Duktape allocation, even when nothing ever
+    // asks for a limit or the current usage. Without the `memory_limit` feature, that cost isn't
+    // worth imposing on every embedder, so the heap falls back to Duktape's own allocator, and the
+    // limit/usage APIs become permanent no-ops (`memory_usage`/`peak_memory_usage` read back `0`,
+    // and a configured limit is never enforced).
+    let ctx = if cfg!(feature = "memory_limit") {
+        ffi::duk_create_heap(
+            Some(tracking_alloc),
+            Some(tracking_realloc),
+            Some(tracking_free),
+            udata as *mut _,
+            Some(fatal_handler),
+        )
+    } else {
+        ffi::duk_create_heap(None, None, None, udata as *mut _, Some(fatal_handler))
+    };
     assert!(!ctx.is_null());
 
     ffi::duk_require_stack(ctx, 1);
@@ -303,6 +369,23 @@ struct Timeout {
 
 pub(crate) struct Udata {
     timeout: Option<Timeout>,
+    cancel_fn: Option<Box<Fn() -> bool>>,
+    interrupt: Arc<AtomicBool>,
+    // A standing handler installed via `Ducc::set_interrupt`, polled on every `timeout_func` check
+    // alongside `interrupt` and `timeout`/`cancel_fn`, for embedders that want to cancel execution
+    // based on something other than a fixed deadline (an instruction budget, a shutdown signal,
+    // etc.). Unlike `cancel_fn`, this is not cleared by `Ducc::exec`.
+    interrupt_fn: Option<Box<FnMut() -> bool>>,
+    // Total bytes currently live on the heap, as tracked by `tracking_alloc`/`tracking_realloc`/
+    // `tracking_free`. This persists across executions, since it reflects the heap's actual
+    // footprint rather than anything scoped to a single `Ducc::exec` call.
+    bytes_allocated: usize,
+    // The largest `bytes_allocated` has ever been, for `Ducc::peak_memory_usage`.
+    peak_bytes_allocated: usize,
+    // A one-time ceiling set via `ExecSettings::memory_limit`, cleared after each `Ducc::exec` call.
+    memory_limit: Option<usize>,
+    // A standing ceiling set via `Ducc::set_memory_limit`, unaffected by `Ducc::exec`.
+    persistent_memory_limit: Option<usize>,
 }
 
 impl Udata {
@@ -316,6 +399,146 @@ impl Udata {
     pub fn clear_timeout(&mut self) {
         self.timeout = None;
     }
+
+    pub fn set_exec_settings(&mut self, settings: ExecSettings) {
+        self.cancel_fn = settings.cancel_fn;
+        match settings.deadline {
+            Some(deadline) => self.set_timeout(deadline),
+            None => self.clear_timeout(),
+        }
+        self.memory_limit = settings.memory_limit;
+    }
+
+    pub fn clear_exec_settings(&mut self) {
+        self.cancel_fn = None;
+        self.clear_timeout();
+        self.memory_limit = None;
+    }
+
+    // Returns the shared cancellation flag backing `Ducc::interrupt_handle`.
+    pub fn interrupt_flag(&self) -> Arc<AtomicBool> {
+        self.interrupt.clone()
+    }
+
+    pub fn set_interrupt<F: 'static + FnMut() -> bool>(&mut self, handler: F) {
+        self.interrupt_fn = Some(Box::new(handler));
+    }
+
+    pub fn clear_interrupt(&mut self) {
+        self.interrupt_fn = None;
+    }
+
+    pub fn set_persistent_memory_limit(&mut self, limit: Option<usize>) {
+        self.persistent_memory_limit = limit;
+    }
+
+    pub fn bytes_allocated(&self) -> usize {
+        self.bytes_allocated
+    }
+
+    pub fn peak_bytes_allocated(&self) -> usize {
+        self.peak_bytes_allocated
+    }
+
+    // The tighter of the one-time `ExecSettings::memory_limit` and the standing
+    // `Ducc::set_memory_limit`, whichever is currently in effect.
+    fn effective_memory_limit(&self) -> Option<usize> {
+        match (self.memory_limit, self.persistent_memory_limit) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+// Every live allocation is prefixed with a header of this many bytes, storing the allocation's
+// requested size so `tracking_realloc`/`tracking_free` can recover it (Duktape's allocator
+// callbacks, unlike `realloc`/`free`, are not passed the old size). Also used as the block
+// alignment, which comfortably covers any alignment Duktape's allocations require.
+const ALLOC_HEADER_SIZE: usize = 16;
+
+unsafe fn block_layout(total_size: usize) -> Layout {
+    Layout::from_size_align_unchecked(total_size, ALLOC_HEADER_SIZE)
+}
+
+unsafe fn block_start(data: *mut u8) -> *mut u8 {
+    data.sub(ALLOC_HEADER_SIZE)
+}
+
+unsafe extern "C" fn tracking_alloc(udata: *mut c_void, size: ffi::duk_size_t) -> *mut c_void {
+    let udata = udata as *mut Udata;
+    let size = size as usize;
+    if size == 0 {
+        return ptr::null_mut();
+    }
+
+    let total_size = ALLOC_HEADER_SIZE + size;
+    if let Some(limit) = (*udata).effective_memory_limit() {
+        if (*udata).bytes_allocated + total_size > limit {
+            return ptr::null_mut();
+        }
+    }
+
+    let block = System.alloc(block_layout(total_size));
+    if block.is_null() {
+        return ptr::null_mut();
+    }
+
+    *(block as *mut usize) = size;
+    (*udata).bytes_allocated += total_size;
+    (*udata).peak_bytes_allocated = (*udata).peak_bytes_allocated.max((*udata).bytes_allocated);
+    block.add(ALLOC_HEADER_SIZE) as *mut c_void
+}
+
+unsafe extern "C" fn tracking_realloc(
+    udata: *mut c_void,
+    data: *mut c_void,
+    size: ffi::duk_size_t,
+) -> *mut c_void {
+    if data.is_null() {
+        return tracking_alloc(udata, size);
+    }
+
+    let udata = udata as *mut Udata;
+    let size = size as usize;
+    let block = block_start(data as *mut u8);
+    let old_size = *(block as *mut usize);
+    let old_total_size = ALLOC_HEADER_SIZE + old_size;
+
+    if size == 0 {
+        (*udata).bytes_allocated -= old_total_size;
+        System.dealloc(block, block_layout(old_total_size));
+        return ptr::null_mut();
+    }
+
+    let total_size = ALLOC_HEADER_SIZE + size;
+    if let Some(limit) = (*udata).effective_memory_limit() {
+        if (*udata).bytes_allocated - old_total_size + total_size > limit {
+            return ptr::null_mut();
+        }
+    }
+
+    let new_block = System.realloc(block, block_layout(old_total_size), total_size);
+    if new_block.is_null() {
+        return ptr::null_mut();
+    }
+
+    *(new_block as *mut usize) = size;
+    (*udata).bytes_allocated = (*udata).bytes_allocated - old_total_size + total_size;
+    (*udata).peak_bytes_allocated = (*udata).peak_bytes_allocated.max((*udata).bytes_allocated);
+    new_block.add(ALLOC_HEADER_SIZE) as *mut c_void
+}
+
+unsafe extern "C" fn tracking_free(udata: *mut c_void, data: *mut c_void) {
+    if data.is_null() {
+        return;
+    }
+
+    let udata = udata as *mut Udata;
+    let block = block_start(data as *mut u8);
+    let old_size = *(block as *mut usize);
+    let old_total_size = ALLOC_HEADER_SIZE + old_size;
+    (*udata).bytes_allocated -= old_total_size;
+    System.dealloc(block, block_layout(old_total_size));
 }
 
 // Unfortunately `ducc_set_exec_timeout_function` sets a global variable, so this applies to all
@@ -334,12 +557,28 @@ unsafe extern "C" fn timeout_func(udata: *mut c_void) -> ffi::duk_bool_t {
     let udata = udata as *mut Udata;
     assert!(!udata.is_null());
 
+    if (*udata).interrupt.load(Ordering::SeqCst) {
+        return 1;
+    }
+
     if let Some(ref timeout) = (*udata).timeout {
         if timeout.start.elapsed() >= timeout.duration {
             return 1;
         }
     }
 
+    if let Some(ref cancel_fn) = (*udata).cancel_fn {
+        if cancel_fn() {
+            return 1;
+        }
+    }
+
+    if let Some(ref mut interrupt_fn) = (*udata).interrupt_fn {
+        if interrupt_fn() {
+            return 1;
+        }
+    }
+
     return 0;
 }
 
@@ -1,4 +1,5 @@
 use array::Array;
+use array_buffer::ArrayBuffer;
 use bytes::Bytes;
 use ducc::Ducc;
 use error::Result;
@@ -8,6 +9,9 @@ use std::iter::FromIterator;
 use std::ops::{Deref, DerefMut};
 use std::{slice, vec};
 use string::String;
+use symbol::Symbol;
+use typed_array::TypedArray;
+use user_data::AnyUserData;
 
 /// A single JavaScript value.
 ///
@@ -35,6 +39,18 @@ pub enum Value<'ducc> {
     Object(Object<'ducc>),
     /// Reference to a JavaScript `Uint8Array`. Contains an internal reference to its parent `Ducc`.
     Bytes(Bytes<'ducc>),
+    /// Reference to a JavaScript typed array view (`Int8Array`, `Float64Array`, etc.), other than
+    /// the plain buffers represented by `Bytes`. Contains an internal reference to its parent
+    /// `Ducc`.
+    TypedArray(TypedArray<'ducc>),
+    /// Reference to a JavaScript `ArrayBuffer`, the backing store typed array views read and write
+    /// through. Contains an internal reference to its parent `Ducc`.
+    ArrayBuffer(ArrayBuffer<'ducc>),
+    /// Reference to an opaque Rust value boxed by `Ducc::create_user_data`. Contains an internal
+    /// reference to its parent `Ducc`.
+    UserData(AnyUserData<'ducc>),
+    /// Reference to a JavaScript `Symbol`. Contains an internal reference to its parent `Ducc`.
+    Symbol(Symbol<'ducc>),
 }
 
 impl<'ducc> Value<'ducc> {
@@ -128,6 +144,46 @@ impl<'ducc> Value<'ducc> {
         if let Value::Bytes(ref value) = *self { Some(value) } else { None }
     }
 
+    /// Returns `true` if this is a `Value::TypedArray`, `false` otherwise.
+    pub fn is_typed_array(&self) -> bool {
+        if let Value::TypedArray(_) = *self { true } else { false }
+    }
+
+    /// Returns `Some` if this is a `Value::TypedArray`, `None` otherwise.
+    pub fn as_typed_array(&self) -> Option<&TypedArray<'ducc>> {
+        if let Value::TypedArray(ref value) = *self { Some(value) } else { None }
+    }
+
+    /// Returns `true` if this is a `Value::ArrayBuffer`, `false` otherwise.
+    pub fn is_array_buffer(&self) -> bool {
+        if let Value::ArrayBuffer(_) = *self { true } else { false }
+    }
+
+    /// Returns `Some` if this is a `Value::ArrayBuffer`, `None` otherwise.
+    pub fn as_array_buffer(&self) -> Option<&ArrayBuffer<'ducc>> {
+        if let Value::ArrayBuffer(ref value) = *self { Some(value) } else { None }
+    }
+
+    /// Returns `true` if this is a `Value::UserData`, `false` otherwise.
+    pub fn is_user_data(&self) -> bool {
+        if let Value::UserData(_) = *self { true } else { false }
+    }
+
+    /// Returns `Some` if this is a `Value::UserData`, `None` otherwise.
+    pub fn as_user_data(&self) -> Option<&AnyUserData<'ducc>> {
+        if let Value::UserData(ref value) = *self { Some(value) } else { None }
+    }
+
+    /// Returns `true` if this is a `Value::Symbol`, `false` otherwise.
+    pub fn is_symbol(&self) -> bool {
+        if let Value::Symbol(_) = *self { true } else { false }
+    }
+
+    /// Returns `Some` if this is a `Value::Symbol`, `None` otherwise.
+    pub fn as_symbol(&self) -> Option<&Symbol<'ducc>> {
+        if let Value::Symbol(ref value) = *self { Some(value) } else { None }
+    }
+
     /// A wrapper around `FromValue::from_value`.
     pub fn into<T: FromValue<'ducc>>(self, ducc: &'ducc Ducc) -> Result<T> {
         T::from_value(self, ducc)
@@ -144,6 +200,10 @@ impl<'ducc> Value<'ducc> {
             Value::Array(_) => "array",
             Value::Object(_) => "object",
             Value::Bytes(_) => "bytes",
+            Value::TypedArray(_) => "typed array",
+            Value::ArrayBuffer(_) => "array buffer",
+            Value::UserData(_) => "user data",
+            Value::Symbol(_) => "symbol",
         }
     }
 }
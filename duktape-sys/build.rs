@@ -6,7 +6,8 @@ fn main() {
     builder.include("duktape")
         .flag("-std=c99")
         .file("duktape/duktape.c")
-        .file("duktape/wrapper.c");
+        .file("duktape/wrapper.c")
+        .file("duktape/extras/cbor/duk_cbor.c");
 
     if cfg!(feature = "use-exec-timeout-check") {
         builder.define("RUST_DUK_USE_EXEC_TIMEOUT_CHECK", None);